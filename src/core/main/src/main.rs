@@ -15,6 +15,7 @@
 #![allow(dead_code)]
 
 use assets::CardAtlas;
+use auction_phase_data::GameConfig;
 use bevy::prelude::*;
 use bevy_mod_picking::DefaultPickingPlugins;
 use display_utils::plugin::DisplayUtilsPlugin;
@@ -39,7 +40,31 @@ fn setup(
     mut updates: EventWriter<PlayPhaseUpdateEvent>,
 ) {
     commands.spawn(Camera2dBundle::default());
-    let game = auction_phase_mutations::new_game(&mut rand::thread_rng());
+    let config = match std::env::args().nth(1) {
+        Some(code) => auction_phase_mutations::config_from_code(&code).unwrap_or_else(|error| {
+            warn!("Ignoring deal code: {error}");
+            random_config()
+        }),
+        None => random_config(),
+    };
+    let game = auction_phase_mutations::new_game(&config);
+    if let Some(code) = auction_phase_mutations::game_code(&config) {
+        commands.spawn(
+            TextBundle::from_section(
+                format!("Deal {code}"),
+                TextStyle { font_size: 20.0, ..default() },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                right: Val::Px(10.0),
+                ..default()
+            }),
+        );
+    }
+    if !game.contract.is_playable(&game.hands) {
+        warn!("Declaring side holds no trumps for {}", game.contract.describe());
+    }
     let card_atlas = CardAtlas::new(asset_server, texture_atlas_layouts);
 
     play_phase_spawn::spawn(&mut commands, &game, &card_atlas, HandIdentifier::North);
@@ -47,5 +72,12 @@ fn setup(
     play_phase_spawn::spawn(&mut commands, &game, &card_atlas, HandIdentifier::South);
     play_phase_spawn::spawn(&mut commands, &game, &card_atlas, HandIdentifier::West);
     commands.insert_resource(game);
+    commands.insert_resource(card_atlas);
     updates.send(PlayPhaseUpdateEvent);
 }
+
+/// Returns the default game options with a new random seed, so that the deal
+/// can be shared via its code.
+fn random_config() -> GameConfig {
+    GameConfig { seed: Some(rand::random()), ..GameConfig::default() }
+}
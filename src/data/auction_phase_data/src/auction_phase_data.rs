@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::{PlayerName, Suit};
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{ensure, Result};
+use primitives::{Card, HandIdentifier, PlayerName, Suit};
+use serde::{Deserialize, Serialize};
 
 /// A bid for a number of tricks a player has committed to winning with a given
 /// trump suit
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Contract {
     /// Player who bid for this contract value
     pub declarer: PlayerName,
@@ -25,3 +29,108 @@ pub struct Contract {
     /// Number of tricks the declarer has committed to winning
     pub bid: u32,
 }
+
+/// Smallest number of tricks a contract can be for, i.e. a one level contract
+/// in bridge.
+pub const MIN_BID: u32 = 7;
+
+/// Largest number of tricks a contract can be for.
+pub const MAX_BID: u32 = 13;
+
+impl Contract {
+    /// Creates a new contract, returning an error if `bid` is not between
+    /// [MIN_BID] and [MAX_BID].
+    pub fn new(declarer: PlayerName, trump: Option<Suit>, bid: u32) -> Result<Self> {
+        let result = Self { declarer, trump, bid };
+        ensure!(result.is_valid(), "Invalid bid {bid}, must be between {MIN_BID} and {MAX_BID}");
+        Ok(result)
+    }
+
+    /// Returns true if the bid for this contract is between [MIN_BID] and
+    /// [MAX_BID].
+    pub fn is_valid(&self) -> bool {
+        (MIN_BID..=MAX_BID).contains(&self.bid)
+    }
+
+    /// Returns true if this contract is for 12 or 13 tricks.
+    pub fn is_slam(&self) -> bool {
+        self.bid >= 12
+    }
+
+    /// Returns false if this is a trump contract and neither hand of the
+    /// declaring side holds a trump in `hands`.
+    pub fn is_playable(&self, hands: &HashMap<HandIdentifier, BTreeSet<Card>>) -> bool {
+        let Some(trump) = self.trump else {
+            return true;
+        };
+        let primary = self.declarer.primary_hand();
+        [primary, primary.partner()]
+            .iter()
+            .filter_map(|hand| hands.get(hand))
+            .flatten()
+            .any(|card| card.suit == trump)
+    }
+
+    /// Returns a human-readable description of this contract, e.g. "8♠ by
+    /// You" or "7 No Trump by Opponents".
+    pub fn describe(&self) -> String {
+        match self.trump {
+            Some(suit) => format!("{}{} by {}", self.bid, suit, self.declarer),
+            None => format!("{} No Trump by {}", self.bid, self.declarer),
+        }
+    }
+}
+
+/// Convention for which hand makes the opening lead of the play phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpeningLeadRule {
+    /// The declarer's primary hand leads to the first trick.
+    #[default]
+    Declarer,
+    /// The hand to the left of the declarer's primary hand leads to the first
+    /// trick, as in contract bridge.
+    LeftOfDeclarer,
+}
+
+impl OpeningLeadRule {
+    /// Returns the hand which makes the opening lead under this rule when
+    /// `declarer` holds the contract.
+    pub fn opening_leader(&self, declarer: PlayerName) -> HandIdentifier {
+        match self {
+            Self::Declarer => declarer.primary_hand(),
+            Self::LeftOfDeclarer => declarer.primary_hand().next(),
+        }
+    }
+}
+
+/// Rule variations which can differ between trick-taking games.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rules {
+    /// Which hand leads to the first trick.
+    pub opening_lead: OpeningLeadRule,
+}
+
+/// Options used to create a new game.
+#[derive(Debug, Clone)]
+pub struct GameConfig {
+    /// Seed for the random number generator used to deal the cards, or None to
+    /// use a random seed.
+    pub seed: Option<u64>,
+    /// Contract the game will be played in.
+    pub contract: Contract,
+    /// Number of cards dealt to each hand.
+    pub hand_size: usize,
+    /// Rule variations to play the game with.
+    pub rules: Rules,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            contract: Contract { declarer: PlayerName::User, trump: Some(Suit::Spades), bid: 8 },
+            hand_size: 13,
+            rules: Rules::default(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use play_phase_data::PlayPhaseData;
+
+/// Identifies a game stored in a [GameArchive].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, PartialOrd, Ord)]
+pub struct GameId(pub u64);
+
+/// Stores past games, including every card played, in serialized form.
+///
+/// Games are currently kept in memory.
+#[derive(Debug, Clone, Default)]
+pub struct GameArchive {
+    games: HashMap<GameId, String>,
+    next_id: u64,
+}
+
+impl GameArchive {
+    /// Stores a copy of `game`, returning the id under which it can be
+    /// retrieved.
+    pub fn save(&mut self, game: &PlayPhaseData) -> serde_json::Result<GameId> {
+        let id = GameId(self.next_id);
+        self.games.insert(id, game.save()?);
+        self.next_id += 1;
+        Ok(id)
+    }
+
+    /// Returns the game stored under `id`, or None if there is no such game.
+    pub fn load(&self, id: GameId) -> Option<PlayPhaseData> {
+        self.games.get(&id).and_then(|saved| PlayPhaseData::load(saved).ok())
+    }
+
+    /// Returns the ids of all stored games, in the order they were saved.
+    pub fn ids(&self) -> Vec<GameId> {
+        let mut result = self.games.keys().copied().collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+}
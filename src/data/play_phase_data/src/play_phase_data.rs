@@ -12,27 +12,398 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use auction_phase_data::Contract;
+use anyhow::{ensure, Result};
+use auction_phase_data::{Contract, Rules};
 use bevy::prelude::*;
-use primitives::{Card, HandIdentifier, PlayerName};
+use primitives::{Card, HandIdentifier, PlayerName, Suit};
+use serde::{Deserialize, Serialize, Serializer};
 
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Serialize, Deserialize)]
 pub struct PlayPhaseData {
-    pub hands: HashMap<HandIdentifier, HashSet<Card>>,
+    /// Cards held by each hand. Stored in sorted order so that iteration is
+    /// deterministic.
+    #[serde(serialize_with = "serialize_hands")]
+    pub hands: HashMap<HandIdentifier, BTreeSet<Card>>,
     pub current_trick: Trick,
     pub completed_tricks: Vec<CompletedTrick>,
+    /// Tricks credited to each side before the first card of this game was
+    /// played.
+    #[serde(default)]
+    pub prior_tricks: PriorTricks,
     pub contract: Contract,
+    /// Rule variations this game is played with.
+    #[serde(default)]
+    pub rules: Rules,
+    /// Rule-relevant occurrences in this game so far, in order, for the user
+    /// interface to narrate.
+    #[serde(default)]
+    pub events: Vec<GameEvent>,
 }
 
 impl PlayPhaseData {
-    pub fn hand(&self, identifier: HandIdentifier) -> impl Iterator<Item = Card> + '_ {
+    /// Serializes this game to a JSON string.
+    pub fn save(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a game previously serialized via [Self::save].
+    pub fn load(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the cards in the indicated hand, in sorted [Card] order.
+    pub fn hand(&self, identifier: HandIdentifier) -> impl DoubleEndedIterator<Item = Card> + '_ {
         self.hands.get(&identifier).unwrap().iter().copied()
     }
+
+    /// Returns the cards in the indicated hand in sorted [Card] order (by suit,
+    /// then by rank), which is the canonical order used for display.
+    pub fn sorted_hand(&self, identifier: HandIdentifier) -> Vec<Card> {
+        self.hand(identifier).collect()
+    }
+
+    /// Trump suit for the current contract, or None if it is a no trump
+    /// contract.
+    pub fn trump(&self) -> Option<Suit> {
+        self.contract.trump
+    }
+
+    /// Player who is declarer for the current contract.
+    pub fn declarer(&self) -> PlayerName {
+        self.contract.declarer
+    }
+
+    /// Returns the card at position `index` in the indicated hand, or None if
+    /// the index is out of bounds.
+    ///
+    /// Indices refer to the hand in [Self::sorted_hand] order.
+    pub fn card_at(&self, identifier: HandIdentifier, index: usize) -> Option<Card> {
+        self.sorted_hand(identifier).get(index).copied()
+    }
+
+    /// Returns the index of a card in the indicated hand, or None if the hand
+    /// does not contain it. This is the inverse of [Self::card_at].
+    pub fn index_of(&self, identifier: HandIdentifier, card: Card) -> Option<usize> {
+        self.sorted_hand(identifier).iter().position(|&c| c == card)
+    }
+
+    /// Returns the hand which currently holds the indicated card, or None if it
+    /// has been played.
+    pub fn holder(&self, card: Card) -> Option<HandIdentifier> {
+        self.hands.iter().find(|(_, hand)| hand.contains(&card)).map(|(&identifier, _)| identifier)
+    }
+
+    /// Returns the cards in the indicated hand grouped by suit, with each group
+    /// in ascending rank order.
+    ///
+    /// Every suit is present in the result, with an empty group for suits the
+    /// hand is void in.
+    pub fn cards_by_suit(&self, identifier: HandIdentifier) -> HashMap<Suit, Vec<Card>> {
+        let mut result =
+            enum_iterator::all::<Suit>().map(|suit| (suit, vec![])).collect::<HashMap<_, _>>();
+        for card in self.hand(identifier) {
+            result.get_mut(&card.suit).unwrap().push(card);
+        }
+        result
+    }
+
+    /// Returns this game with `count` tricks credited as already played,
+    /// `declarer_won` of them to the declaring side and the rest to the
+    /// defenders, e.g. to practice an endgame from a realistic trick count.
+    ///
+    /// The cards of these tricks are not modeled, so the hands and the
+    /// opening lead are unaffected.
+    ///
+    /// Panics if `declarer_won` is greater than `count`.
+    pub fn with_prior_tricks(mut self, count: usize, declarer_won: usize) -> Self {
+        assert!(declarer_won <= count, "Declarer cannot win {declarer_won} of {count} tricks");
+        self.prior_tricks = PriorTricks { declarer: declarer_won, defenders: count - declarer_won };
+        self
+    }
+
+    /// Returns the number of completed tricks won by the partnership controlled
+    /// by `side`, including its [PriorTricks].
+    ///
+    /// The final trick of a game is not counted until another card is played,
+    /// since it remains the current trick.
+    pub fn tricks_won(&self, side: PlayerName) -> usize {
+        let prior = self.prior_tricks.won_by(side == self.declarer());
+        prior
+            + self
+                .completed_tricks
+                .iter()
+                .filter(|completed| completed.winning_side() == side)
+                .count()
+    }
+
+    /// Returns the number of additional tricks the declarer must win to make
+    /// the contract, given the number of tricks they have won so far.
+    pub fn tricks_still_needed(&self, tricks_won_by_declarer: usize) -> usize {
+        (self.contract.bid as usize).saturating_sub(tricks_won_by_declarer)
+    }
+
+    /// Returns the player whose side won this game, given the number of tricks
+    /// won by the declarer: the declarer if the contract was made, otherwise
+    /// the defender.
+    pub fn winning_side(&self, tricks_won_by_declarer: usize) -> PlayerName {
+        if self.tricks_still_needed(tricks_won_by_declarer) == 0 {
+            self.declarer()
+        } else {
+            self.declarer().opponent()
+        }
+    }
+
+    /// Returns the result of this game split into its components, given the
+    /// number of tricks won by the declarer.
+    pub fn score_breakdown(&self, tricks_won_by_declarer: usize) -> ScoreBreakdown {
+        ScoreBreakdown::new(&self.contract, tricks_won_by_declarer)
+    }
+
+    /// Returns the hand which leads to the first trick, as chosen by the
+    /// opening lead rule for this game.
+    pub fn opening_leader(&self) -> HandIdentifier {
+        self.rules.opening_lead.opening_leader(self.declarer())
+    }
+
+    /// Returns the hand which is dummy for the current contract, i.e. the
+    /// partner of the declarer's primary hand.
+    pub fn dummy(&self) -> HandIdentifier {
+        self.declarer().primary_hand().partner()
+    }
+
+    /// Returns the total high card points dealt to the declarer's two hands,
+    /// including cards which have since been played.
+    pub fn declaring_side_points(&self) -> usize {
+        let primary = self.declarer().primary_hand();
+        let initial = self.initial_hands();
+        [primary, primary.partner()]
+            .iter()
+            .filter_map(|hand| initial.get(hand))
+            .flatten()
+            .map(|card| card.rank.high_card_points())
+            .sum()
+    }
+
+    /// Returns the number of trumps held by both hands of the partnership
+    /// controlled by `side`, or 0 in a no trump contract.
+    pub fn trump_length(&self, side: PlayerName) -> usize {
+        let Some(trump) = self.trump() else {
+            return 0;
+        };
+        self.combined_hand(side).iter().filter(|card| card.suit == trump).count()
+    }
+
+    /// Returns the cards held by both hands of the partnership controlled by
+    /// `side`, in sorted order.
+    pub fn combined_hand(&self, side: PlayerName) -> Vec<Card> {
+        let primary = side.primary_hand();
+        let mut result = self.hand(primary).chain(self.hand(primary.partner())).collect::<Vec<_>>();
+        result.sort();
+        result
+    }
+
+    /// Returns the number of cards of each suit held by both hands of the
+    /// partnership controlled by `side`.
+    pub fn combined_suit_counts(&self, side: PlayerName) -> HashMap<Suit, usize> {
+        let mut result =
+            enum_iterator::all::<Suit>().map(|suit| (suit, 0)).collect::<HashMap<_, _>>();
+        for card in self.combined_hand(side) {
+            *result.get_mut(&card.suit).unwrap() += 1;
+        }
+        result
+    }
+
+    /// Returns every card played so far, in the order in which they were
+    /// played.
+    pub fn played_cards(&self) -> impl Iterator<Item = &PlayedCard> + '_ {
+        self.completed_tricks
+            .iter()
+            .flat_map(|completed| completed.trick.cards.iter())
+            .chain(self.current_trick.cards.iter())
+    }
+
+    /// Returns the number of the trick currently being played, starting from 1
+    /// for the first trick, counting any [PriorTricks].
+    ///
+    /// A complete trick keeps its number until the next card is led.
+    pub fn current_trick_number(&self) -> usize {
+        self.prior_tricks.total() + self.completed_tricks.len() + 1
+    }
+
+    /// Returns each completed trick in order as a tuple of its trick number
+    /// (starting from 1 and counting any [PriorTricks]), the cards played to it
+    /// in play order, and the hand which won it.
+    #[allow(clippy::type_complexity)]
+    pub fn trick_history(&self) -> Vec<(usize, Vec<(HandIdentifier, Card)>, HandIdentifier)> {
+        self.completed_tricks
+            .iter()
+            .enumerate()
+            .map(|(index, completed)| {
+                let plays = completed
+                    .trick
+                    .cards
+                    .iter()
+                    .map(|played| (played.played_by, played.card))
+                    .collect();
+                (self.prior_tricks.total() + index + 1, plays, completed.winner)
+            })
+            .collect()
+    }
+
+    /// Returns the hands as they were dealt, before any cards were played.
+    pub fn initial_hands(&self) -> HashMap<HandIdentifier, BTreeSet<Card>> {
+        let mut result = self.hands.clone();
+        for played in self.played_cards() {
+            result.get_mut(&played.played_by).unwrap().insert(played.card);
+        }
+        result
+    }
+
+    /// Returns true if no cards have been played yet, i.e. the next card
+    /// played will be the opening lead.
+    pub fn is_opening_lead(&self) -> bool {
+        self.completed_tricks.is_empty() && self.current_trick.cards.is_empty()
+    }
+
+    /// Returns a new game with the same deal, restarted from the opening lead,
+    /// in which each partnership holds the cards the other partnership was
+    /// dealt.
+    ///
+    /// Every hand moves one seat, so that the declarer's cards go to the other
+    /// player's primary hand, and the contract passes to the other player. When
+    /// the user is declarer hands move clockwise (South to West), otherwise
+    /// counterclockwise, so swapping twice restores the original game. Each
+    /// hand keeps the same neighbors, which lets a deal be replayed from the
+    /// opponents' side.
+    pub fn swap_partnerships(&self) -> Self {
+        let seats = match self.declarer() {
+            PlayerName::User => 1,
+            PlayerName::Opponent => 3,
+        };
+        let swap = |identifier: HandIdentifier| identifier.rotate(seats);
+        Self {
+            hands: self
+                .initial_hands()
+                .into_iter()
+                .map(|(hand, cards)| (swap(hand), cards))
+                .collect(),
+            current_trick: Trick::default(),
+            completed_tricks: vec![],
+            prior_tricks: self.prior_tricks,
+            contract: Contract { declarer: self.declarer().opponent(), ..self.contract.clone() },
+            rules: self.rules,
+            events: vec![],
+        }
+    }
+
+    /// Checks that this is a correctly finished game: every hand is empty and
+    /// one trick of four cards has been played for each card originally dealt
+    /// to a hand.
+    pub fn verify_complete(&self) -> Result<()> {
+        ensure!(self.is_game_over(), "Hands are not empty");
+        let dealt = self.initial_hands().values().map(|hand| hand.len()).max().unwrap_or_default();
+        let current = Some(&self.current_trick).filter(|trick| !trick.cards.is_empty());
+        let tricks = self.completed_tricks.iter().map(|completed| &completed.trick).chain(current);
+        let mut played = 0;
+        for (index, trick) in tricks.enumerate() {
+            ensure!(trick.cards.len() == 4, "Trick {index} has {} cards", trick.cards.len());
+            played += 1;
+        }
+        let expected = self.prior_tricks.total() + dealt;
+        let found = self.prior_tricks.total() + played;
+        ensure!(found == expected, "Expected {expected} tricks but found {found}");
+        Ok(())
+    }
+
+    /// Returns true if every hand has been played out.
+    pub fn is_game_over(&self) -> bool {
+        self.hands.values().all(|hand| hand.is_empty())
+    }
+}
+
+/// Serializes hands in sorted order, so that the same game always produces the
+/// same output.
+fn serialize_hands<S: Serializer>(
+    hands: &HashMap<HandIdentifier, BTreeSet<Card>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    hands.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Number of cards of a single suit which have been played so far and which
+/// have not yet been played.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SuitPlayCount {
+    pub played: usize,
+    pub outstanding: usize,
+}
+
+/// Tricks credited to each side without being played, see
+/// [PlayPhaseData::with_prior_tricks].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorTricks {
+    /// Number of prior tricks won by the declaring side.
+    pub declarer: usize,
+    /// Number of prior tricks won by the defenders.
+    pub defenders: usize,
+}
+
+impl PriorTricks {
+    /// Total number of prior tricks.
+    pub fn total(&self) -> usize {
+        self.declarer + self.defenders
+    }
+
+    /// Number of prior tricks won by the declaring side if `declaring` is
+    /// true, or by the defenders otherwise.
+    pub fn won_by(&self, declaring: bool) -> usize {
+        if declaring {
+            self.declarer
+        } else {
+            self.defenders
+        }
+    }
+}
+
+/// Result of a game relative to its contract.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    /// Number of tricks won by the declarer which counted towards the
+    /// contract, at most the number bid.
+    pub contract_tricks: usize,
+    /// Number of tricks won by the declarer beyond the number bid.
+    pub overtricks: usize,
+    /// Number of additional tricks the declarer needed to make the contract.
+    pub undertricks: usize,
 }
 
-#[derive(Debug, Clone)]
+impl ScoreBreakdown {
+    /// Returns the result of `contract` when the declarer won
+    /// `tricks_won_by_declarer` tricks.
+    pub fn new(contract: &Contract, tricks_won_by_declarer: usize) -> Self {
+        let bid = contract.bid as usize;
+        Self {
+            contract_tricks: tricks_won_by_declarer.min(bid),
+            overtricks: tricks_won_by_declarer.saturating_sub(bid),
+            undertricks: bid.saturating_sub(tricks_won_by_declarer),
+        }
+    }
+
+    /// Returns true if the declarer won at least as many tricks as they bid.
+    pub fn is_made(&self) -> bool {
+        self.undertricks == 0
+    }
+
+    /// Returns the number of tricks by which the contract was made (positive)
+    /// or defeated (negative).
+    pub fn net(&self) -> i32 {
+        self.overtricks as i32 - self.undertricks as i32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompletedTrick {
     /// Cards which were played in this trick.
     pub trick: Trick,
@@ -40,14 +411,29 @@ pub struct CompletedTrick {
     pub winner: HandIdentifier,
 }
 
-#[derive(Debug, Clone, Default)]
+impl CompletedTrick {
+    /// Returns the partnership which won this trick.
+    pub fn winning_side(&self) -> PlayerName {
+        self.winner.player_name()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Trick {
     /// Cards played in this trick, in sequence
     pub cards: Vec<PlayedCard>,
 }
 
+impl Trick {
+    /// Returns the card which was led to this trick, or None if no card has
+    /// been played yet.
+    pub fn lead_card(&self) -> Option<Card> {
+        self.cards.first().map(|played| played.card)
+    }
+}
+
 /// Represents a card played to a trick
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PlayedCard {
     /// Player who played this card
     pub played_by: HandIdentifier,
@@ -55,7 +441,27 @@ pub struct PlayedCard {
     pub card: Card,
 }
 
+/// Something which happened as the result of an action, derived when the
+/// action is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The indicated hand won the trick which was just completed.
+    TrickWon(HandIdentifier),
+    /// The indicated hand played a trump to a trick on which the given suit was
+    /// led.
+    Ruffed(HandIdentifier, Suit),
+    /// The final trick was played and the declarer won enough tricks to make
+    /// the contract.
+    ContractMade,
+    /// The final trick was played and the declarer did not win enough tricks
+    /// to make the contract.
+    ContractDefeated,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum PlayPhaseAction {
     PlayCard(PlayerName, HandIdentifier, Card),
+    /// Moves a complete current trick to the list of completed tricks, clearing
+    /// the table before the next lead.
+    ContinueToNextTrick,
 }
@@ -15,11 +15,14 @@
 use std::fmt;
 
 use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
 
 /// Represents the four traditional playing card suits.
 ///
 /// Suits are ordered Clubs < Diamonds < Hearts < Spades.
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord)]
+#[derive(
+    PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum Suit {
     Clubs,
     Diamonds,
@@ -27,6 +30,32 @@ pub enum Suit {
     Spades,
 }
 
+impl Suit {
+    /// Returns true for Hearts and Diamonds.
+    pub fn is_red(&self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+
+    /// Returns the other suit of the same color as this suit.
+    pub fn same_color(&self) -> Suit {
+        match self {
+            Suit::Clubs => Suit::Spades,
+            Suit::Diamonds => Suit::Hearts,
+            Suit::Hearts => Suit::Diamonds,
+            Suit::Spades => Suit::Clubs,
+        }
+    }
+
+    /// Returns the two suits of the opposite color to this suit.
+    pub fn other_colors(&self) -> [Suit; 2] {
+        if self.is_red() {
+            [Suit::Clubs, Suit::Spades]
+        } else {
+            [Suit::Diamonds, Suit::Hearts]
+        }
+    }
+}
+
 impl fmt::Display for Suit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -43,7 +72,9 @@ impl fmt::Display for Suit {
 }
 
 /// Represents the standard playing card ranks, with Aces high
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord)]
+#[derive(
+    PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum Rank {
     Two,
     Three,
@@ -60,6 +91,20 @@ pub enum Rank {
     Ace,
 }
 
+impl Rank {
+    /// Returns the standard high card point value of this rank: 4 for an Ace,
+    /// 3 for a King, 2 for a Queen, 1 for a Jack and 0 otherwise.
+    pub fn high_card_points(&self) -> usize {
+        match self {
+            Rank::Ace => 4,
+            Rank::King => 3,
+            Rank::Queen => 2,
+            Rank::Jack => 1,
+            _ => 0,
+        }
+    }
+}
+
 impl fmt::Display for Rank {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -86,7 +131,7 @@ impl fmt::Display for Rank {
 
 /// Represents one of the 52 standard playing cards. Card ordering is by [Suit]
 /// first and then by [Rank].
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
@@ -102,10 +147,26 @@ impl Card {
     pub fn new(suit: Suit, rank: Rank) -> Self {
         Self { suit, rank }
     }
+
+    /// Returns every card of the same suit ranked above this one, in
+    /// ascending order.
+    pub fn higher_in_suit(&self) -> impl Iterator<Item = Card> {
+        let (suit, rank) = (self.suit, self.rank);
+        enum_iterator::all::<Rank>().filter(move |&r| r > rank).map(move |r| Card::new(suit, r))
+    }
+
+    /// Returns every card of the same suit ranked below this one, in
+    /// ascending order.
+    pub fn lower_in_suit(&self) -> impl Iterator<Item = Card> {
+        let (suit, rank) = (self.suit, self.rank);
+        enum_iterator::all::<Rank>().filter(move |&r| r < rank).map(move |r| Card::new(suit, r))
+    }
 }
 
 /// Represents one of the four hands in an Oak game.
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Ord, PartialOrd)]
+#[derive(
+    PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Ord, PartialOrd, Serialize, Deserialize,
+)]
 pub enum HandIdentifier {
     /// Dummy partner of human player
     North,
@@ -128,6 +189,34 @@ impl HandIdentifier {
         }
     }
 
+    /// Returns the index of this position in turn sequence, starting from
+    /// North = 0. This gives a stable ordering of positions.
+    pub fn seat_index(&self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+        }
+    }
+
+    /// Returns the position with the given [Self::seat_index], wrapping around
+    /// for values above 3.
+    pub fn from_seat_index(index: usize) -> Self {
+        Self::North.rotate(index)
+    }
+
+    /// Returns the position `n` seats after this one in turn sequence.
+    pub fn rotate(&self, n: usize) -> Self {
+        (0..n % 4).fold(*self, |position, _| position.next())
+    }
+
+    /// Returns the number of seats from this position to `other` in turn
+    /// sequence, between 0 and 3.
+    pub fn seats_until(&self, other: HandIdentifier) -> usize {
+        (other.seat_index() + 4 - self.seat_index()) % 4
+    }
+
     /// Returns the partner position of this position
     pub fn partner(&self) -> Self {
         match self {
@@ -147,13 +236,21 @@ impl HandIdentifier {
 }
 
 /// Identifies one of the two players participating in a round
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence)]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Serialize, Deserialize)]
 pub enum PlayerName {
     User,
     Opponent,
 }
 
 impl PlayerName {
+    /// Returns the other player in this round.
+    pub fn opponent(&self) -> Self {
+        match self {
+            PlayerName::User => PlayerName::Opponent,
+            PlayerName::Opponent => PlayerName::User,
+        }
+    }
+
     /// Returns the hand which this player can see at the beginning of the
     /// auction phase.
     ///
@@ -166,3 +263,16 @@ impl PlayerName {
         }
     }
 }
+
+impl fmt::Display for PlayerName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PlayerName::User => "You",
+                PlayerName::Opponent => "Opponents",
+            }
+        )
+    }
+}
@@ -25,6 +25,25 @@ pub struct AnchoredTransform {
     pub vertical: VerticalAnchor,
 }
 
+impl AnchoredTransform {
+    /// Returns the world position of this anchor in a window of the given
+    /// size.
+    pub fn position(&self, window_size: Vec2) -> Vec2 {
+        let (width, height) = (window_size.x, window_size.y);
+        let x = match self.horizontal {
+            HorizontalAnchor::Left => width / -2.0,
+            HorizontalAnchor::Center => 0.0,
+            HorizontalAnchor::Right => width / 2.0,
+        };
+        let y = match self.vertical {
+            VerticalAnchor::Top => height / 2.0,
+            VerticalAnchor::Center => 0.0,
+            VerticalAnchor::Bottom => height / -2.0,
+        };
+        Vec2::new(x, y)
+    }
+}
+
 pub enum HorizontalAnchor {
     Left,
     Center,
@@ -43,18 +62,8 @@ pub fn on_resize_system(
 ) {
     for e in resize_reader.read() {
         for (anchored, mut transform) in query.iter_mut() {
-            let (width, height) = (e.width, e.height);
-            let x = match anchored.horizontal {
-                HorizontalAnchor::Left => width / -2.0,
-                HorizontalAnchor::Center => 0.0,
-                HorizontalAnchor::Right => width / 2.0,
-            };
-            let y = match anchored.vertical {
-                VerticalAnchor::Top => height / 2.0,
-                VerticalAnchor::Center => 0.0,
-                VerticalAnchor::Bottom => height / -2.0,
-            };
-            *transform = Transform::from_xyz(x, y, transform.translation.z);
+            let position = anchored.position(Vec2::new(e.width, e.height));
+            *transform = Transform::from_translation(position.extend(transform.translation.z));
         }
     }
 }
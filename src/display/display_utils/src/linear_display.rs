@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 
 /// Controls whether a [LinearDisplay] shows its contents in a horizontal row or
@@ -35,14 +37,41 @@ pub struct LinearDisplay {
     pub direction: LinearDisplayDirection,
 }
 
-pub fn update(query: Query<(&LinearDisplay, &Children)>, mut transforms: Query<&mut Transform>) {
-    for (display, children_iterator) in query.iter() {
+/// Marker component for children of a [LinearDisplay] whose [Transform] is
+/// temporarily controlled by some other system, e.g. while being dragged.
+///
+/// These children keep their slot in the display, but are not repositioned.
+#[derive(Component)]
+pub struct ManualPosition;
+
+/// Positions the children of each [LinearDisplay].
+///
+/// A display is only laid out again when its children or the display itself
+/// change, or when one of its children stops being manually positioned.
+pub fn update(
+    query: Query<(Entity, Ref<LinearDisplay>, Ref<Children>)>,
+    parents: Query<&Parent>,
+    mut released: RemovedComponents<ManualPosition>,
+    mut transforms: Query<&mut Transform, Without<ManualPosition>>,
+) {
+    let released = released
+        .read()
+        .filter_map(|entity| parents.get(entity).ok())
+        .map(|parent| parent.get())
+        .collect::<HashSet<_>>();
+    for (entity, display, children_iterator) in query.iter() {
+        if !display.is_changed() && !children_iterator.is_changed() && !released.contains(&entity) {
+            continue;
+        }
+
         let children = children_iterator.iter().collect::<Vec<_>>();
         let count = children.len();
         match count {
             0 => {}
             1 => {
-                let mut transform = transforms.get_mut(*children[0]).unwrap();
+                let Ok(mut transform) = transforms.get_mut(*children[0]) else {
+                    continue;
+                };
                 match display.direction {
                     LinearDisplayDirection::Horizontal => {
                         transform.translation.x = 0.0;
@@ -56,7 +85,9 @@ pub fn update(query: Query<(&LinearDisplay, &Children)>, mut transforms: Query<&
             _ => {
                 let increment = display.size / (count - 1) as f32;
                 for (i, child) in children.into_iter().enumerate() {
-                    let mut transform = transforms.get_mut(*child).unwrap();
+                    let Ok(mut transform) = transforms.get_mut(*child) else {
+                        continue;
+                    };
                     let offset = (-display.size / 2.0) + (i as f32 * increment);
                     match display.direction {
                         LinearDisplayDirection::Horizontal => {
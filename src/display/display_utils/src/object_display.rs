@@ -42,11 +42,21 @@ pub struct ObjectDisplay {
     pub position: ObjectDisplayPosition,
 }
 
+/// Moves each [Displayable] to the [ObjectDisplay] for its position.
+///
+/// Children are only reassigned when a [Displayable] or [ObjectDisplay] has
+/// been added or changed.
+#[allow(clippy::type_complexity)]
 pub fn update(
     mut commands: Commands,
     parent_query: Query<(Entity, &ObjectDisplay)>,
     children_query: Query<(Entity, &Displayable)>,
+    changed: Query<(), Or<(Changed<Displayable>, Added<ObjectDisplay>)>>,
 ) {
+    if changed.is_empty() {
+        return;
+    }
+
     let mut displayables = children_query.iter().collect::<Vec<_>>();
     displayables.sort_by_key(|(_, d)| *d);
     for (parent, _) in &parent_query {
@@ -0,0 +1,100 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_review::PlayPhaseReview;
+
+use crate::play_phase_events::{self, TrickDisplayOrder};
+use crate::play_phase_spawn::CardComponent;
+
+/// Minimum time between two successive card movements.
+pub const ANIMATION_DELAY: Duration = Duration::from_millis(300);
+
+/// Queue of game states waiting to be displayed.
+///
+/// Each card play is displayed as a separate step, at most one per
+/// [ANIMATION_DELAY], so that several cards played in quick succession move
+/// one after another instead of all at once.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AnimationQueue {
+    pending: VecDeque<PlayPhaseData>,
+    shown_plays: usize,
+    cooldown: Duration,
+}
+
+impl AnimationQueue {
+    /// Queues one state for each card played in `data` since the last call,
+    /// or the current state if no new cards have been played.
+    pub fn push_updates(&mut self, data: &PlayPhaseData) {
+        let mut review = PlayPhaseReview::new(data);
+        if review.last_step() <= self.shown_plays {
+            self.pending.push_back(data.clone());
+        } else {
+            while review.step() < self.shown_plays {
+                review.next_step();
+            }
+            while let Some(state) = review.next_step() {
+                self.pending.push_back(state);
+            }
+        }
+        self.shown_plays = review.last_step();
+    }
+
+    /// Advances the queue by `elapsed`, returning the next state to display if
+    /// the previous movement has finished.
+    pub fn tick(&mut self, elapsed: Duration) -> Option<PlayPhaseData> {
+        self.cooldown = self.cooldown.saturating_sub(elapsed);
+        if !self.cooldown.is_zero() {
+            return None;
+        }
+        let result = self.pending.pop_front()?;
+        self.cooldown = ANIMATION_DELAY;
+        Some(result)
+    }
+
+    /// Returns true if there are no states waiting to be displayed.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Moves cards to their positions in the next queued state, if any.
+pub fn animate(
+    mut commands: Commands,
+    time: Res<Time>,
+    order: Res<TrickDisplayOrder>,
+    mut queue: ResMut<AnimationQueue>,
+    cards: Query<(&CardComponent, Entity)>,
+) {
+    let Some(state) = queue.tick(time.delta()) else {
+        return;
+    };
+
+    let mut to_update = vec![];
+    for (card, entity) in cards.iter() {
+        match play_phase_events::card_position(&state, card.data, *order) {
+            Some(displayable) => to_update.push((entity, displayable)),
+            None => warn!("Card not found {}", card.data),
+        }
+    }
+
+    to_update.sort_by_key(|(_, d)| *d);
+    for (entity, displayable) in to_update {
+        commands.entity(entity).insert(displayable);
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use play_phase_data::{PlayPhaseAction, PlayPhaseData};
+use play_phase_rules::{play_phase_actions, play_phase_flags};
+
+use crate::game_settings::GameSettings;
+use crate::play_phase_events::PlayPhaseUpdateEvent;
+
+/// Clears a completed trick from the table once it has been displayed for
+/// [GameSettings::auto_continue_delay], if [GameSettings::auto_continue] is
+/// enabled.
+pub fn update(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut data: ResMut<PlayPhaseData>,
+    mut elapsed: Local<Duration>,
+    mut updates: EventWriter<PlayPhaseUpdateEvent>,
+) {
+    if !settings.auto_continue || !play_phase_flags::can_continue_to_next_trick(&data) {
+        *elapsed = Duration::ZERO;
+        return;
+    }
+
+    *elapsed += time.delta();
+    if *elapsed >= settings.auto_continue_delay {
+        *elapsed = Duration::ZERO;
+        play_phase_actions::handle_action(&mut data, PlayPhaseAction::ContinueToNextTrick);
+        updates.send(PlayPhaseUpdateEvent);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::{PlayPhaseAction, PlayPhaseData};
+use play_phase_rules::{play_phase_actions, play_phase_queries};
+
+use crate::game_settings::GameSettings;
+use crate::play_phase_events::PlayPhaseUpdateEvent;
+
+/// Plays the next card automatically if the player to act has no choice about
+/// which card to play and [GameSettings::auto_play_forced] is enabled.
+pub fn auto_play_forced(
+    settings: Res<GameSettings>,
+    mut data: ResMut<PlayPhaseData>,
+    mut updates: EventWriter<PlayPhaseUpdateEvent>,
+) {
+    if !settings.auto_play_forced {
+        return;
+    }
+
+    if let Some((hand, card)) = play_phase_queries::forced_play(&data) {
+        play_phase_actions::handle_action(
+            &mut data,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+        updates.send(PlayPhaseUpdateEvent);
+    }
+}
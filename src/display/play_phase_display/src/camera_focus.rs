@@ -0,0 +1,77 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use primitives::HandIdentifier;
+
+use crate::play_phase_spawn;
+
+/// Camera zoom level used while focused on a hand, where 1.0 shows the whole
+/// table.
+pub const FOCUS_SCALE: f32 = 0.6;
+
+/// Fraction of the remaining distance to its target the camera covers per
+/// second.
+pub const FOCUS_SPEED: f32 = 5.0;
+
+/// Hand the camera is zoomed in on, or None to show the whole table.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CameraFocus {
+    pub hand: Option<HandIdentifier>,
+}
+
+/// Returns the camera position and zoom scale for the indicated focus in a
+/// window of the given size.
+///
+/// When zoomed in on a hand, the camera is positioned so that the edge of the
+/// screen where that hand is anchored remains at the edge of the view.
+pub fn camera_target(focus: Option<HandIdentifier>, window_size: Vec2) -> (Vec2, f32) {
+    match focus {
+        Some(hand) => {
+            let anchor = play_phase_spawn::hand_anchor(hand).position(window_size);
+            (anchor * (1.0 - FOCUS_SCALE), FOCUS_SCALE)
+        }
+        None => (Vec2::ZERO, 1.0),
+    }
+}
+
+/// Moves the camera focus to the next hand when the F key is pressed, cycling
+/// through each hand in turn and then back to the whole table.
+pub fn cycle_focus(keys: Res<ButtonInput<KeyCode>>, mut focus: ResMut<CameraFocus>) {
+    if keys.just_pressed(KeyCode::KeyF) {
+        focus.hand = match focus.hand {
+            Some(hand) => enum_iterator::next(&hand),
+            None => enum_iterator::first::<HandIdentifier>(),
+        };
+    }
+}
+
+/// Moves the camera towards the target for the current [CameraFocus].
+pub fn update(
+    time: Res<Time>,
+    focus: Res<CameraFocus>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+) {
+    let window_size =
+        windows.get_single().map(|w| Vec2::new(w.width(), w.height())).unwrap_or_default();
+    let (target, scale) = camera_target(focus.hand, window_size);
+    let fraction = (FOCUS_SPEED * time.delta_seconds()).min(1.0);
+    for (mut transform, mut projection) in &mut cameras {
+        let position = transform.translation.truncate().lerp(target, fraction);
+        transform.translation = position.extend(transform.translation.z);
+        projection.scale += (scale - projection.scale) * fraction;
+    }
+}
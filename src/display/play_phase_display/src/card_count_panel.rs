@@ -0,0 +1,73 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_queries;
+use primitives::Suit;
+
+use crate::game_settings::GameSettings;
+
+/// Marker component for the text panel showing card counts per suit.
+#[derive(Component)]
+pub struct CardCountPanel;
+
+/// Spawns the card count panel, initially hidden.
+pub fn spawn(mut commands: Commands) {
+    commands.spawn((
+        CardCountPanel,
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section("", TextStyle { font_size: 24.0, ..default() }).with_style(
+                Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    left: Val::Px(10.0),
+                    ..default()
+                },
+            )
+        },
+    ));
+}
+
+/// Toggles [GameSettings::show_card_counts] when the C key is pressed.
+pub fn toggle(keys: Res<ButtonInput<KeyCode>>, mut settings: ResMut<GameSettings>) {
+    if keys.just_pressed(KeyCode::KeyC) {
+        settings.show_card_counts = !settings.show_card_counts;
+    }
+}
+
+/// Updates the contents and visibility of the card count panel.
+pub fn update(
+    data: Res<PlayPhaseData>,
+    settings: Res<GameSettings>,
+    mut panels: Query<(&mut Text, &mut Visibility), With<CardCountPanel>>,
+) {
+    if !data.is_changed() && !settings.is_changed() {
+        return;
+    }
+
+    let counts = play_phase_queries::suit_play_counts(&data);
+    for (mut text, mut visibility) in &mut panels {
+        *visibility =
+            if settings.show_card_counts { Visibility::Visible } else { Visibility::Hidden };
+        text.sections[0].value = enum_iterator::reverse_all::<Suit>()
+            .map(|suit| {
+                let count = counts[&suit];
+                format!("{suit} played {} / outstanding {}", count.played, count.outstanding)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
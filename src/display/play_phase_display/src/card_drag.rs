@@ -0,0 +1,139 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use bevy_mod_picking::prelude::*;
+use display_utils::linear_display::ManualPosition;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_flags;
+use primitives::{Card, HandIdentifier};
+
+use crate::card_hover::HoverOrigin;
+use crate::confirm_play::PendingPlay;
+use crate::game_settings::GameSettings;
+use crate::game_state::GameState;
+use crate::play_phase_events::PlayPhaseUpdateEvent;
+use crate::play_phase_spawn::CardComponent;
+
+/// Distance from the center of the screen, in logical pixels, within which a
+/// dropped card is considered to have been dropped on the current trick.
+pub const TRICK_AREA_RADIUS: f32 = 150.0;
+
+/// Component for a card which is being dragged by the user.
+#[derive(Component)]
+pub struct Dragging {
+    /// Position of the card before the drag started, which it returns to if
+    /// it is not played.
+    pub origin: Vec3,
+    /// True once the drag has been released. The component is removed on the
+    /// following update so that the click generated by the release is
+    /// ignored.
+    pub ended: bool,
+}
+
+/// Returns true if a card dropped at `position` in world space should be
+/// played to the current trick.
+pub fn drop_plays_card(
+    data: &PlayPhaseData,
+    hand: HandIdentifier,
+    card: Card,
+    position: Vec2,
+) -> bool {
+    position.length() <= TRICK_AREA_RADIUS && play_phase_flags::can_play_card(data, hand, card)
+}
+
+/// Handles `card` being dropped at `position` in world space. Returns true if
+/// the card was played.
+///
+/// A drop over the current trick is handled like a click on the card via
+/// [PendingPlay::select], so with `confirm` set it only selects the card. Any
+/// other drop clears the pending selection.
+pub fn handle_drop(
+    data: &mut PlayPhaseData,
+    pending: &mut PendingPlay,
+    confirm: bool,
+    hand: HandIdentifier,
+    card: Card,
+    position: Vec2,
+) -> bool {
+    if drop_plays_card(data, hand, card, position) {
+        pending.select(data, confirm, hand, card)
+    } else {
+        pending.cancel();
+        false
+    }
+}
+
+pub fn on_drag_start(
+    event: Listener<Pointer<DragStart>>,
+    mut commands: Commands,
+    transforms: Query<(&Transform, Option<&HoverOrigin>)>,
+) {
+    if let Ok((transform, hover_origin)) = transforms.get(event.target) {
+        let origin = hover_origin.map_or(transform.translation, |h| h.0);
+        commands
+            .entity(event.target)
+            .remove::<HoverOrigin>()
+            .insert((ManualPosition, Dragging { origin, ended: false }));
+    }
+}
+
+pub fn on_drag(event: Listener<Pointer<Drag>>, mut transforms: Query<&mut Transform>) {
+    if let Ok(mut transform) = transforms.get_mut(event.target) {
+        transform.translation.x += event.delta.x;
+        transform.translation.y -= event.delta.y;
+    }
+}
+
+/// Returns the dragged card to its original position and passes the drop to
+/// [handle_drop], which plays or selects it if it was released over the
+/// current trick. Cards are never played while the game is paused.
+#[allow(clippy::too_many_arguments)]
+pub fn on_drag_end(
+    event: Listener<Pointer<DragEnd>>,
+    mut commands: Commands,
+    state: Res<GameState>,
+    settings: Res<GameSettings>,
+    mut data: ResMut<PlayPhaseData>,
+    mut pending: ResMut<PendingPlay>,
+    mut updates: EventWriter<PlayPhaseUpdateEvent>,
+    mut cards: Query<(&CardComponent, &GlobalTransform, &mut Transform, &mut Dragging)>,
+) {
+    let Ok((card, global, mut transform, mut dragging)) = cards.get_mut(event.target) else {
+        return;
+    };
+    let position = global.translation().truncate();
+    transform.translation = dragging.origin;
+    dragging.ended = true;
+    commands.entity(event.target).remove::<ManualPosition>();
+
+    let Some(hand) = data.holder(card.data) else {
+        return;
+    };
+    if *state == GameState::Paused {
+        return;
+    }
+    if handle_drop(&mut data, &mut pending, settings.confirm_plays, hand, card.data, position) {
+        updates.send(PlayPhaseUpdateEvent);
+    }
+}
+
+/// Removes the [Dragging] component from cards whose drag has been released.
+pub fn clear_finished_drags(mut commands: Commands, query: Query<(Entity, &Dragging)>) {
+    for (entity, dragging) in &query {
+        if dragging.ended {
+            commands.entity(entity).remove::<Dragging>();
+        }
+    }
+}
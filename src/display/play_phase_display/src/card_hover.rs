@@ -0,0 +1,69 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use display_utils::linear_display::ManualPosition;
+use display_utils::object_display::{Displayable, ObjectDisplayPosition};
+use primitives::PlayerName;
+
+use crate::card_drag::Dragging;
+
+/// Distance in logical pixels by which a hovered card is raised.
+pub const HOVER_RAISE: f32 = 25.0;
+
+/// Amount added to the Z position of a hovered card so it renders above its
+/// neighbors.
+pub const HOVER_Z: f32 = 100.0;
+
+/// Marker component for a card which the pointer is currently over.
+#[derive(Component, Clone)]
+pub struct Hovered;
+
+/// Position of a raised card before it was raised.
+#[derive(Component)]
+pub struct HoverOrigin(pub Vec3);
+
+/// Returns true if a card displayed at `position` should be raised while
+/// hovered. Only cards in the user's hands respond to hovering.
+pub fn can_hover(position: ObjectDisplayPosition) -> bool {
+    matches!(position, ObjectDisplayPosition::InHand(hand) if hand.player_name() == PlayerName::User)
+}
+
+/// Raises hovered cards and returns cards which are no longer hovered to their
+/// original position.
+#[allow(clippy::type_complexity)]
+pub fn update(
+    mut commands: Commands,
+    mut cards: Query<
+        (Entity, &mut Transform, &Displayable, Has<Hovered>, Option<&HoverOrigin>),
+        Without<Dragging>,
+    >,
+) {
+    for (entity, mut transform, displayable, hovered, origin) in &mut cards {
+        let raise = hovered && can_hover(displayable.position);
+        match origin {
+            None if raise => {
+                commands
+                    .entity(entity)
+                    .insert((ManualPosition, HoverOrigin(transform.translation)));
+                transform.translation += Vec3::new(0.0, HOVER_RAISE, HOVER_Z);
+            }
+            Some(&HoverOrigin(origin)) if !raise => {
+                transform.translation = origin;
+                commands.entity(entity).remove::<(ManualPosition, HoverOrigin)>();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use assets::CardAtlas;
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use primitives::HandIdentifier;
+
+use crate::play_phase_spawn::CardComponent;
+
+/// Tracks whether the dummy hand has been turned face up.
+///
+/// The dummy is kept face down until the opening lead has been made.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DummyReveal {
+    pub revealed: bool,
+}
+
+impl DummyReveal {
+    /// Marks the dummy as revealed if the opening lead has been made. Returns
+    /// true if the dummy was revealed by this call.
+    pub fn update(&mut self, data: &PlayPhaseData) -> bool {
+        if self.revealed || data.is_opening_lead() {
+            return false;
+        }
+        self.revealed = true;
+        true
+    }
+}
+
+/// Debugging option to display every hand face up.
+///
+/// Toggled with the D key. This only affects rendering, not the game rules.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DebugReveal {
+    pub enabled: bool,
+}
+
+/// Returns true if cards held by the indicated hand should be displayed face
+/// up.
+pub fn is_hand_visible(
+    data: &PlayPhaseData,
+    hand: HandIdentifier,
+    reveal: &DummyReveal,
+    debug: &DebugReveal,
+) -> bool {
+    debug.enabled || hand != data.dummy() || reveal.revealed
+}
+
+/// Toggles [DebugReveal] when the D key is pressed.
+pub fn toggle_debug_reveal(keys: Res<ButtonInput<KeyCode>>, mut debug: ResMut<DebugReveal>) {
+    if keys.just_pressed(KeyCode::KeyD) {
+        debug.enabled = !debug.enabled;
+    }
+}
+
+/// Turns the dummy's cards face up once the opening lead has been made, and
+/// updates every card's face when [DebugReveal] changes.
+pub fn update_card_faces(
+    data: Res<PlayPhaseData>,
+    card_atlas: Res<CardAtlas>,
+    debug: Res<DebugReveal>,
+    mut reveal: ResMut<DummyReveal>,
+    mut cards: Query<(&CardComponent, &mut Handle<Image>, &mut TextureAtlas)>,
+) {
+    if !reveal.update(&data) && !debug.is_changed() {
+        return;
+    }
+    for (card, mut image, mut atlas) in &mut cards {
+        let visible =
+            data.holder(card.data).is_none_or(|hand| is_hand_visible(&data, hand, &reveal, &debug));
+        (*image, *atlas) = card_atlas.get_card(card.data, visible);
+    }
+}
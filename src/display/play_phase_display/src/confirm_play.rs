@@ -0,0 +1,100 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::{PlayPhaseAction, PlayPhaseData};
+use play_phase_rules::{play_phase_actions, play_phase_flags};
+use primitives::{Card, HandIdentifier, PlayerName};
+
+use crate::card_hover::Hovered;
+use crate::play_phase_events::PlayPhaseUpdateEvent;
+
+/// Tint applied to a card which has been selected but not yet played.
+pub const PENDING_COLOR: Color = Color::rgb(0.6, 0.8, 1.0);
+
+/// Card the user has clicked on which is waiting for a second click to be
+/// played, used when [crate::game_settings::GameSettings::confirm_plays] is
+/// enabled.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingPlay {
+    pub selected: Option<(HandIdentifier, Card)>,
+}
+
+impl PendingPlay {
+    /// Handles the user clicking on `card` in the indicated hand. Returns true
+    /// if the card was played.
+    ///
+    /// If `confirm` is false, or the card is already selected, it is played
+    /// immediately. Otherwise it becomes the selected card. Clicking a card
+    /// which cannot be played clears the selection.
+    pub fn select(
+        &mut self,
+        data: &mut PlayPhaseData,
+        confirm: bool,
+        hand: HandIdentifier,
+        card: Card,
+    ) -> bool {
+        if !play_phase_flags::can_play_card(data, hand, card) {
+            self.cancel();
+            return false;
+        }
+        if confirm && self.selected != Some((hand, card)) {
+            self.selected = Some((hand, card));
+            return false;
+        }
+        self.selected = None;
+        play_phase_actions::handle_action(
+            data,
+            PlayPhaseAction::PlayCard(PlayerName::User, hand, card),
+        );
+        true
+    }
+
+    /// Plays the selected card, if any. Returns true if a card was played.
+    pub fn confirm(&mut self, data: &mut PlayPhaseData) -> bool {
+        match self.selected {
+            Some((hand, card)) => self.select(data, false, hand, card),
+            None => false,
+        }
+    }
+
+    /// Clears the selected card without playing it.
+    pub fn cancel(&mut self) {
+        self.selected = None;
+    }
+}
+
+/// Plays the selected card when Enter is pressed, and clears the selection
+/// when Escape is pressed or the mouse is clicked away from any card.
+pub fn handle_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    hovered: Query<(), With<Hovered>>,
+    mut pending: ResMut<PendingPlay>,
+    mut data: ResMut<PlayPhaseData>,
+    mut updates: EventWriter<PlayPhaseUpdateEvent>,
+) {
+    if pending.selected.is_none() {
+        return;
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        if pending.confirm(&mut data) {
+            updates.send(PlayPhaseUpdateEvent);
+        }
+    } else if keys.just_pressed(KeyCode::Escape)
+        || (mouse.just_pressed(MouseButton::Left) && hovered.is_empty())
+    {
+        pending.cancel();
+    }
+}
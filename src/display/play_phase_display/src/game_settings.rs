@@ -0,0 +1,54 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// User-configurable options for the play phase.
+#[derive(Resource, Debug, Clone)]
+pub struct GameSettings {
+    /// If true, cards are played automatically whenever the player to act has
+    /// exactly one legal play.
+    pub auto_play_forced: bool,
+    /// If true, a panel showing how many cards of each suit have been played is
+    /// displayed.
+    pub show_card_counts: bool,
+    /// If true, the cards of each completed trick move towards the hand which
+    /// won it before being removed from the table.
+    pub sweep_completed_tricks: bool,
+    /// If true, each completed trick is cleared from the table automatically
+    /// after [Self::auto_continue_delay] instead of remaining until the next
+    /// card is played.
+    pub auto_continue: bool,
+    /// How long a completed trick remains on the table before it is cleared
+    /// when [Self::auto_continue] is enabled.
+    pub auto_continue_delay: Duration,
+    /// If true, clicking a card only selects it, and a second click (or the
+    /// Enter key) is required to play it.
+    pub confirm_plays: bool,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            auto_play_forced: false,
+            show_card_counts: false,
+            sweep_completed_tricks: true,
+            auto_continue: false,
+            auto_continue_delay: Duration::from_secs(1),
+            confirm_plays: false,
+        }
+    }
+}
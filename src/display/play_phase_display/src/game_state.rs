@@ -0,0 +1,41 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+
+/// Whether the game is currently accepting moves.
+///
+/// While paused, the board remains displayed but card plays from the user and
+/// automatic plays are ignored.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GameState {
+    #[default]
+    Playing,
+    Paused,
+}
+
+/// Run condition which is true if the game is not paused.
+pub fn is_playing(state: Res<GameState>) -> bool {
+    *state == GameState::Playing
+}
+
+/// Pauses or resumes the game when the P key is pressed.
+pub fn toggle_pause(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<GameState>) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        *state = match *state {
+            GameState::Playing => GameState::Paused,
+            GameState::Paused => GameState::Playing,
+        };
+    }
+}
@@ -12,17 +12,81 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bevy::app::{App, Plugin, Update};
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::ecs::schedule::IntoSystemConfigs;
 
-use crate::play_phase_events::PlayPhaseUpdateEvent;
+use crate::animation_queue::AnimationQueue;
+use crate::camera_focus::CameraFocus;
+use crate::card_visibility::{DebugReveal, DummyReveal};
+use crate::confirm_play::PendingPlay;
+use crate::game_settings::GameSettings;
+use crate::game_state::GameState;
+use crate::play_phase_events::{PlayPhaseUpdateEvent, TrickDisplayOrder};
+use crate::trump_override::SetTrumpEvent;
+use crate::turn_timer::TurnTimer;
 
+pub mod animation_queue;
+pub mod auto_continue;
+pub mod auto_play;
+pub mod camera_focus;
+pub mod card_count_panel;
+pub mod card_drag;
+pub mod card_hover;
+pub mod card_visibility;
+pub mod confirm_play;
+pub mod game_settings;
+pub mod game_state;
 pub mod play_phase_events;
 pub mod play_phase_spawn;
+pub mod result_overlay;
+pub mod trick_highlight;
+pub mod trick_sweep;
+pub mod trump_override;
+pub mod turn_timer;
 
 pub struct PlayPhaseDisplayPlugin;
 
 impl Plugin for PlayPhaseDisplayPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<PlayPhaseUpdateEvent>().add_systems(Update, play_phase_events::sync_state);
+        app.add_event::<PlayPhaseUpdateEvent>()
+            .add_event::<SetTrumpEvent>()
+            .init_resource::<GameSettings>()
+            .init_resource::<TurnTimer>()
+            .init_resource::<DummyReveal>()
+            .init_resource::<DebugReveal>()
+            .init_resource::<AnimationQueue>()
+            .init_resource::<TrickDisplayOrder>()
+            .init_resource::<PendingPlay>()
+            .init_resource::<GameState>()
+            .init_resource::<CameraFocus>()
+            .add_systems(Startup, (card_count_panel::spawn, result_overlay::spawn))
+            .add_systems(
+                Update,
+                (
+                    (card_count_panel::toggle, card_count_panel::update).chain(),
+                    card_drag::clear_finished_drags,
+                    card_hover::update,
+                    (card_visibility::toggle_debug_reveal, card_visibility::update_card_faces)
+                        .chain(),
+                    game_state::toggle_pause,
+                    result_overlay::update,
+                    (camera_focus::cycle_focus, camera_focus::update).chain(),
+                    (
+                        confirm_play::handle_input.run_if(game_state::is_playing),
+                        trick_highlight::update,
+                    )
+                        .chain(),
+                    (trick_sweep::start, trick_sweep::update).chain(),
+                    (trump_override::cycle_trump, trump_override::set_trump).chain(),
+                    turn_timer::update.run_if(game_state::is_playing),
+                    (
+                        auto_continue::update.run_if(game_state::is_playing),
+                        auto_play::auto_play_forced.run_if(game_state::is_playing),
+                        play_phase_events::sync_state,
+                        animation_queue::animate,
+                    )
+                        .chain(),
+                ),
+            );
     }
 }
@@ -17,37 +17,51 @@ use display_utils::object_display::{Displayable, ObjectDisplayPosition};
 use play_phase_data::PlayPhaseData;
 use primitives::{Card, HandIdentifier};
 
-use crate::play_phase_spawn::CardComponent;
+use crate::animation_queue::AnimationQueue;
 
 #[derive(Event)]
 pub struct PlayPhaseUpdateEvent;
 
+/// Order in which cards in the current trick are displayed.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrickDisplayOrder {
+    /// Cards are displayed in the order in which they were played.
+    #[default]
+    PlayOrder,
+    /// Cards are displayed in [HandIdentifier] order, regardless of who led.
+    SeatOrder,
+}
+
+/// Queues the current game state for display when the game is updated.
 pub fn sync_state(
-    mut commands: Commands,
     data: Res<PlayPhaseData>,
     mut updates: EventReader<PlayPhaseUpdateEvent>,
-    cards: Query<(&CardComponent, Entity)>,
+    mut queue: ResMut<AnimationQueue>,
 ) {
-    let mut to_update = vec![];
     if !updates.is_empty() {
         updates.clear();
-        for (card, entity) in cards.iter() {
-            to_update.push((entity, card_position(&data, card.data)));
-        }
-    }
-
-    to_update.sort_by_key(|(_, d)| *d);
-    for (entity, displayable) in to_update {
-        commands.entity(entity).insert(displayable);
+        queue.push_updates(&data);
     }
 }
 
-fn card_position(data: &PlayPhaseData, card: Card) -> Displayable {
+/// Returns the position at which a card should be displayed, or None if the
+/// card cannot be found in the current trick, a completed trick, or any hand.
+///
+/// Cards in the current trick are sorted according to `order`.
+pub fn card_position(
+    data: &PlayPhaseData,
+    card: Card,
+    order: TrickDisplayOrder,
+) -> Option<Displayable> {
     if let Some(position) = data.current_trick.cards.iter().position(|c| c.card == card) {
-        return Displayable {
-            position: ObjectDisplayPosition::InTrick(data.current_trick.cards[position].played_by),
-            sorting_key: position,
-        };
+        let played_by = data.current_trick.cards[position].played_by;
+        return Some(Displayable {
+            position: ObjectDisplayPosition::InTrick(played_by),
+            sorting_key: match order {
+                TrickDisplayOrder::PlayOrder => position,
+                TrickDisplayOrder::SeatOrder => played_by.seat_index(),
+            },
+        });
     }
 
     if let Some(position) = data
@@ -56,22 +70,20 @@ fn card_position(data: &PlayPhaseData, card: Card) -> Displayable {
         .flat_map(|completed| completed.trick.cards.iter())
         .position(|c| c.card == card)
     {
-        return Displayable {
+        return Some(Displayable {
             position: ObjectDisplayPosition::CompletedTrick,
             sorting_key: position,
-        };
+        });
     }
 
     for hand_id in enum_iterator::all::<HandIdentifier>() {
-        let mut hand = data.hand(hand_id).collect::<Vec<_>>();
-        hand.sort();
-        if let Some(p) = hand.iter().position(|&c| c == card) {
-            return Displayable {
+        if let Some(p) = data.index_of(hand_id, card) {
+            return Some(Displayable {
                 position: ObjectDisplayPosition::InHand(hand_id),
                 sorting_key: p,
-            };
+            });
         }
     }
 
-    panic!("Card not found {card}")
+    None
 }
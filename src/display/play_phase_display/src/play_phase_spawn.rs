@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::f32::consts::FRAC_PI_2;
+
 use assets::CardAtlas;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
@@ -19,10 +21,15 @@ use bevy_mod_picking::prelude::*;
 use display_utils::anchored_transform::{AnchoredTransform, HorizontalAnchor, VerticalAnchor};
 use display_utils::linear_display::{LinearDisplay, LinearDisplayDirection};
 use display_utils::object_display::{ObjectDisplay, ObjectDisplayPosition};
-use play_phase_data::{PlayPhaseAction, PlayPhaseData};
-use play_phase_rules::{play_phase_actions, play_phase_flags};
-use primitives::{Card, HandIdentifier, PlayerName};
+use play_phase_data::PlayPhaseData;
+use primitives::{Card, HandIdentifier};
 
+use crate::card_drag::{self, Dragging};
+use crate::card_hover::Hovered;
+use crate::card_visibility::{self, DebugReveal, DummyReveal};
+use crate::confirm_play::PendingPlay;
+use crate::game_settings::GameSettings;
+use crate::game_state::GameState;
 use crate::play_phase_events::PlayPhaseUpdateEvent;
 
 #[derive(Component)]
@@ -30,44 +37,58 @@ pub struct CardComponent {
     pub data: Card,
 }
 
-pub fn spawn(
-    commands: &mut Commands,
-    game: &PlayPhaseData,
-    card_atlas: &CardAtlas,
-    identifier: HandIdentifier,
-) {
-    let mut hand = game.hands.get(&identifier).unwrap().iter().collect::<Vec<_>>();
-    hand.sort();
+/// Returns the rotation applied to cards dealt to the indicated hand.
+///
+/// Cards in the East and West hands are turned sideways, with their bottom
+/// edge against the side of the screen, so that they read as side hands.
+pub fn hand_rotation(identifier: HandIdentifier) -> Quat {
+    match identifier {
+        HandIdentifier::North | HandIdentifier::South => Quat::IDENTITY,
+        HandIdentifier::East => Quat::from_rotation_z(FRAC_PI_2),
+        HandIdentifier::West => Quat::from_rotation_z(-FRAC_PI_2),
+    }
+}
+
+/// Returns the screen anchor for the cards in the indicated hand.
+pub fn hand_anchor(identifier: HandIdentifier) -> AnchoredTransform {
     let (horizontal, vertical) = match identifier {
         HandIdentifier::North => (HorizontalAnchor::Center, VerticalAnchor::Top),
         HandIdentifier::East => (HorizontalAnchor::Right, VerticalAnchor::Center),
         HandIdentifier::South => (HorizontalAnchor::Center, VerticalAnchor::Bottom),
         HandIdentifier::West => (HorizontalAnchor::Left, VerticalAnchor::Center),
     };
-    let card_visible = match identifier {
-        HandIdentifier::North | HandIdentifier::South => true,
-        HandIdentifier::East | HandIdentifier::West => true,
-    };
+    AnchoredTransform { horizontal, vertical }
+}
+
+pub fn spawn(
+    commands: &mut Commands,
+    game: &PlayPhaseData,
+    card_atlas: &CardAtlas,
+    identifier: HandIdentifier,
+) {
+    let hand = game.sorted_hand(identifier);
+    let card_visible = card_visibility::is_hand_visible(
+        game,
+        identifier,
+        &DummyReveal::default(),
+        &DebugReveal::default(),
+    );
     let direction = match identifier {
         HandIdentifier::North | HandIdentifier::South => LinearDisplayDirection::Horizontal,
         HandIdentifier::East | HandIdentifier::West => LinearDisplayDirection::Vertical,
     };
     let sprite_anchor = match identifier {
         HandIdentifier::North => Anchor::TopCenter,
-        HandIdentifier::East => Anchor::CenterRight,
-        HandIdentifier::South => Anchor::BottomCenter,
-        HandIdentifier::West => Anchor::CenterLeft,
+        HandIdentifier::East | HandIdentifier::South | HandIdentifier::West => Anchor::BottomCenter,
     };
 
-    commands
-        .spawn((SpatialBundle::default(), AnchoredTransform { horizontal, vertical }))
-        .with_children(|parent| {
-            parent.spawn((
-                ObjectDisplay { position: ObjectDisplayPosition::InHand(identifier) },
-                SpatialBundle::default(),
-                LinearDisplay { size: 225.0, direction },
-            ));
-        });
+    commands.spawn((SpatialBundle::default(), hand_anchor(identifier))).with_children(|parent| {
+        parent.spawn((
+            ObjectDisplay { position: ObjectDisplayPosition::InHand(identifier) },
+            SpatialBundle::default(),
+            LinearDisplay { size: 225.0, direction },
+        ));
+    });
 
     commands
         .spawn((
@@ -85,7 +106,7 @@ pub fn spawn(
             ));
         });
 
-    for &card in hand {
+    for card in hand {
         let (texture, atlas) = card_atlas.get_card(card, card_visible);
         commands.spawn((
             CardComponent { data: card },
@@ -93,20 +114,30 @@ pub fn spawn(
                 texture,
                 atlas,
                 sprite: Sprite { anchor: sprite_anchor, ..default() },
+                transform: Transform::from_rotation(hand_rotation(identifier)),
                 ..default()
             },
             On::<Pointer<Click>>::run(
-                move |mut data: ResMut<PlayPhaseData>,
-                      mut updates: EventWriter<PlayPhaseUpdateEvent>| {
-                    if play_phase_flags::can_play_card(&data, identifier, card) {
-                        play_phase_actions::handle_action(
-                            &mut data,
-                            PlayPhaseAction::PlayCard(PlayerName::User, identifier, card),
-                        );
+                move |event: Listener<Pointer<Click>>,
+                      settings: Res<GameSettings>,
+                      mut data: ResMut<PlayPhaseData>,
+                      mut pending: ResMut<PendingPlay>,
+                      mut updates: EventWriter<PlayPhaseUpdateEvent>,
+                      state: Res<GameState>,
+                      dragging: Query<(), With<Dragging>>| {
+                    if dragging.contains(event.target) || *state == GameState::Paused {
+                        return;
+                    }
+                    if pending.select(&mut data, settings.confirm_plays, identifier, card) {
                         updates.send(PlayPhaseUpdateEvent);
                     }
                 },
             ),
+            On::<Pointer<Over>>::target_insert(Hovered),
+            On::<Pointer<Out>>::target_remove::<Hovered>(),
+            On::<Pointer<DragStart>>::run(card_drag::on_drag_start),
+            On::<Pointer<Drag>>::run(card_drag::on_drag),
+            On::<Pointer<DragEnd>>::run(card_drag::on_drag_end),
         ));
     }
 }
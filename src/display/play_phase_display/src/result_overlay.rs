@@ -0,0 +1,59 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_result;
+
+/// Marker component for the text overlay shown when a game ends.
+#[derive(Component)]
+pub struct ResultOverlay;
+
+/// Spawns the result overlay, initially hidden.
+pub fn spawn(mut commands: Commands) {
+    commands.spawn((
+        ResultOverlay,
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section("", TextStyle { font_size: 48.0, ..default() })
+                .with_text_justify(JustifyText::Center)
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(40.0),
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    ..default()
+                })
+        },
+    ));
+}
+
+/// Shows the result of the game once it is over, and hides the overlay
+/// otherwise.
+pub fn update(
+    data: Res<PlayPhaseData>,
+    mut overlays: Query<(&mut Text, &mut Visibility), With<ResultOverlay>>,
+) {
+    if !data.is_changed() {
+        return;
+    }
+
+    let screen = play_phase_result::for_game(&data);
+    for (mut text, mut visibility) in &mut overlays {
+        *visibility = if screen.is_some() { Visibility::Visible } else { Visibility::Hidden };
+        if let Some(screen) = &screen {
+            text.sections[0].value = format!("{}\n{}", screen.title, screen.summary);
+        }
+    }
+}
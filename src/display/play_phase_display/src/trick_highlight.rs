@@ -0,0 +1,54 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_queries;
+use primitives::Card;
+
+use crate::confirm_play::{PendingPlay, PENDING_COLOR};
+use crate::play_phase_spawn::CardComponent;
+
+/// Tint applied to the card which won the trick on the table.
+pub const HIGHLIGHT_COLOR: Color = Color::rgb(1.0, 0.85, 0.4);
+
+/// Returns the card to highlight as the winner of the current trick, or None
+/// if the current trick is not yet complete.
+pub fn highlighted_card(data: &PlayPhaseData) -> Option<Card> {
+    play_phase_queries::trick_result(&data.current_trick, data.trump()).map(|(_, card)| card)
+}
+
+/// Tints the winning card of a completed trick while it remains on the table,
+/// clearing the tint once the next trick begins. A card selected via
+/// [PendingPlay] is tinted as well.
+pub fn update(
+    data: Res<PlayPhaseData>,
+    pending: Res<PendingPlay>,
+    mut cards: Query<(&CardComponent, &mut Sprite)>,
+) {
+    if !data.is_changed() && !pending.is_changed() {
+        return;
+    }
+    let highlighted = highlighted_card(&data);
+    let selected = pending.selected.map(|(_, card)| card);
+    for (card, mut sprite) in &mut cards {
+        sprite.color = if Some(card.data) == highlighted {
+            HIGHLIGHT_COLOR
+        } else if Some(card.data) == selected {
+            PENDING_COLOR
+        } else {
+            Color::WHITE
+        };
+    }
+}
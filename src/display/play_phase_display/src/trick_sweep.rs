@@ -0,0 +1,100 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use display_utils::linear_display::ManualPosition;
+use display_utils::object_display::{Displayable, ObjectDisplayPosition};
+use play_phase_data::PlayPhaseData;
+use primitives::HandIdentifier;
+
+use crate::game_settings::GameSettings;
+use crate::play_phase_spawn::{self, CardComponent};
+
+/// Time taken to move a completed trick to the hand which won it.
+pub const SWEEP_DURATION: Duration = Duration::from_millis(400);
+
+/// Component for a card which is moving towards the hand which won its trick.
+#[derive(Component)]
+pub struct TrickSweep {
+    pub start: Vec3,
+    pub target: Vec3,
+    pub timer: Timer,
+}
+
+/// Marker component for a card whose trick has already been swept off the
+/// table.
+#[derive(Component)]
+pub struct Swept;
+
+/// Returns the world position of the screen anchor for the indicated hand in
+/// a window of the given size, towards which tricks it wins are swept.
+pub fn sweep_target(winner: HandIdentifier, window_size: Vec2) -> Vec2 {
+    play_phase_spawn::hand_anchor(winner).position(window_size)
+}
+
+/// Starts sweeping cards which have just been moved to a completed trick.
+#[allow(clippy::type_complexity)]
+pub fn start(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    data: Res<PlayPhaseData>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cards: Query<
+        (Entity, &CardComponent, &Displayable, &GlobalTransform),
+        (Changed<Displayable>, Without<TrickSweep>, Without<Swept>),
+    >,
+) {
+    if !settings.sweep_completed_tricks {
+        return;
+    }
+    let window_size =
+        windows.get_single().map(|w| Vec2::new(w.width(), w.height())).unwrap_or_default();
+    for (entity, card, displayable, global) in &cards {
+        if displayable.position != ObjectDisplayPosition::CompletedTrick {
+            continue;
+        }
+        let Some(completed) = data
+            .completed_tricks
+            .iter()
+            .find(|completed| completed.trick.cards.iter().any(|c| c.card == card.data))
+        else {
+            continue;
+        };
+        let start = global.translation();
+        let target = sweep_target(completed.winner, window_size).extend(start.z);
+        commands.entity(entity).insert((
+            ManualPosition,
+            TrickSweep { start, target, timer: Timer::new(SWEEP_DURATION, TimerMode::Once) },
+        ));
+    }
+}
+
+/// Moves sweeping cards towards their target, hiding them once they arrive.
+pub fn update(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cards: Query<(Entity, &mut Transform, &mut Visibility, &mut TrickSweep)>,
+) {
+    for (entity, mut transform, mut visibility, mut sweep) in &mut cards {
+        sweep.timer.tick(time.delta());
+        transform.translation = sweep.start.lerp(sweep.target, sweep.timer.fraction());
+        if sweep.timer.finished() {
+            *visibility = Visibility::Hidden;
+            commands.entity(entity).remove::<TrickSweep>().insert(Swept);
+        }
+    }
+}
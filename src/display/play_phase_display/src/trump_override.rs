@@ -0,0 +1,59 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use primitives::Suit;
+
+use crate::play_phase_events::PlayPhaseUpdateEvent;
+
+/// Debugging event which replaces the trump suit of the current contract, or
+/// makes it a no trump contract if the suit is None.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SetTrumpEvent(pub Option<Suit>);
+
+/// Sends a [SetTrumpEvent] for the next trump suit when the T key is pressed,
+/// cycling through each suit in turn and then no trump.
+pub fn cycle_trump(
+    keys: Res<ButtonInput<KeyCode>>,
+    data: Res<PlayPhaseData>,
+    mut events: EventWriter<SetTrumpEvent>,
+) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        let next = match data.trump() {
+            Some(suit) => enum_iterator::next(&suit),
+            None => enum_iterator::first::<Suit>(),
+        };
+        events.send(SetTrumpEvent(next));
+    }
+}
+
+/// Applies [SetTrumpEvent]s before the opening lead has been made.
+///
+/// Events received once play has started are ignored, since the winners of
+/// completed tricks, and therefore the order in which hands led, depend on the
+/// trump suit.
+pub fn set_trump(
+    mut events: EventReader<SetTrumpEvent>,
+    mut data: ResMut<PlayPhaseData>,
+    mut updates: EventWriter<PlayPhaseUpdateEvent>,
+) {
+    for SetTrumpEvent(trump) in events.read() {
+        if !data.is_opening_lead() {
+            continue;
+        }
+        data.contract.trump = *trump;
+        updates.send(PlayPhaseUpdateEvent);
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::game_settings::GameSettings;
+
+/// Tracks the total time each hand has spent deciding which card to play.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TurnTimer {
+    totals: HashMap<HandIdentifier, Duration>,
+}
+
+impl TurnTimer {
+    /// Total time the indicated hand has spent as the next hand to play.
+    pub fn total(&self, hand: HandIdentifier) -> Duration {
+        self.totals.get(&hand).copied().unwrap_or_default()
+    }
+
+    /// Adds `elapsed` to the total time for the indicated hand.
+    pub fn record(&mut self, hand: HandIdentifier, elapsed: Duration) {
+        *self.totals.entry(hand).or_default() += elapsed;
+    }
+}
+
+/// Credits the time elapsed since the last update to the hand which is next to
+/// play.
+///
+/// While a complete trick is on the table its winner is deciding on the next
+/// lead, so the time is credited to them. The timer is only paused when
+/// [GameSettings::auto_continue] is about to clear the trick.
+pub fn update(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    data: Res<PlayPhaseData>,
+    mut timer: ResMut<TurnTimer>,
+) {
+    if data.is_game_over() || (settings.auto_continue && data.current_trick.cards.len() == 4) {
+        return;
+    }
+    timer.record(play_phase_queries::next_to_play(&data), time.delta());
+}
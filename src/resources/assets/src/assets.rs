@@ -19,6 +19,7 @@ pub fn load_card(asset_server: Res<AssetServer>) -> Handle<Image> {
     asset_server.load("cards/clubKing.png")
 }
 
+#[derive(Resource)]
 pub struct CardAtlas {
     atlas: Handle<Image>,
     layout: Handle<TextureAtlasLayout>,
@@ -12,39 +12,151 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
 use std::slice::ChunksExact;
 
-use auction_phase_data::Contract;
-use play_phase_data::{PlayPhaseData, Trick};
+use anyhow::{bail, ensure, Context, Result};
+use auction_phase_data::{Contract, GameConfig};
+use play_phase_data::{PlayPhaseData, PriorTricks, Trick};
 use primitives::{Card, HandIdentifier, PlayerName, Rank, Suit};
 use rand::prelude::SliceRandom;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
-pub fn new_game(rng: &mut impl Rng) -> PlayPhaseData {
+/// Deals a new game using the options in `config`.
+///
+/// Panics if `config.hand_size` is not between 1 and 13.
+pub fn new_game(config: &GameConfig) -> PlayPhaseData {
+    assert!((1..=13).contains(&config.hand_size), "Invalid hand size {}", config.hand_size);
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
     let mut cards = Vec::new();
     for suit in enum_iterator::all::<Suit>() {
         for rank in enum_iterator::all::<Rank>() {
             cards.push(Card::new(suit, rank))
         }
     }
-    cards.shuffle(rng);
+    cards.shuffle(&mut rng);
 
-    let mut chunks = cards.chunks_exact(13);
+    let mut chunks = cards.chunks_exact(config.hand_size);
     let mut hands = HashMap::new();
     hands.insert(HandIdentifier::North, build_hand(&mut chunks));
     hands.insert(HandIdentifier::East, build_hand(&mut chunks));
     hands.insert(HandIdentifier::South, build_hand(&mut chunks));
     hands.insert(HandIdentifier::West, build_hand(&mut chunks));
+    debug_assert!(
+        config.hand_size != 13 || is_complete_deal(&hands),
+        "Dealt hands do not partition the deck"
+    );
 
     PlayPhaseData {
         hands,
         current_trick: Trick::default(),
         completed_tricks: vec![],
-        contract: Contract { declarer: PlayerName::User, trump: Some(Suit::Spades), bid: 8 },
+        prior_tricks: PriorTricks::default(),
+        contract: config.contract.clone(),
+        rules: config.rules,
+        events: vec![],
     }
 }
 
-fn build_hand(chunks: &mut ChunksExact<Card>) -> HashSet<Card> {
-    HashSet::from_iter(chunks.next().expect("Invalid deck size").iter().copied())
+/// Deals a new game from a shareable code previously produced by [game_code].
+///
+/// See [config_from_code] for the format of the code.
+pub fn new_game_from_code(code: &str) -> Result<PlayPhaseData> {
+    Ok(new_game(&config_from_code(code)?))
+}
+
+/// Parses a shareable deal code into a [GameConfig].
+///
+/// A code is a deal seed written in hexadecimal, optionally followed by a dash
+/// and a contract: the number of tricks bid, the trump suit letter (or "N" for
+/// no trump) and the declarer ("U" for User or "O" for Opponent), e.g.
+/// "2f1c-8SU". If the contract is omitted the default contract is used.
+pub fn config_from_code(code: &str) -> Result<GameConfig> {
+    let (seed, contract) = match code.trim().split_once('-') {
+        Some((seed, contract)) => (seed, Some(contract)),
+        None => (code.trim(), None),
+    };
+    let seed = u64::from_str_radix(seed, 16).with_context(|| format!("Invalid seed {seed}"))?;
+    let mut result = GameConfig { seed: Some(seed), ..GameConfig::default() };
+    if let Some(contract) = contract {
+        result.contract = parse_contract(contract)?;
+    }
+    Ok(result)
+}
+
+/// Returns the shareable code for a game dealt with `config`, or None if the
+/// config does not specify a seed.
+pub fn game_code(config: &GameConfig) -> Option<String> {
+    let contract = &config.contract;
+    let strain = match contract.trump {
+        Some(Suit::Clubs) => 'C',
+        Some(Suit::Diamonds) => 'D',
+        Some(Suit::Hearts) => 'H',
+        Some(Suit::Spades) => 'S',
+        None => 'N',
+    };
+    let declarer = match contract.declarer {
+        PlayerName::User => 'U',
+        PlayerName::Opponent => 'O',
+    };
+    config.seed.map(|seed| format!("{seed:x}-{}{strain}{declarer}", contract.bid))
+}
+
+fn parse_contract(contract: &str) -> Result<Contract> {
+    let mut chars = contract.chars();
+    let declarer = match chars.next_back() {
+        Some('U') => PlayerName::User,
+        Some('O') => PlayerName::Opponent,
+        _ => bail!("Invalid declarer in contract {contract}"),
+    };
+    let trump = match chars.next_back() {
+        Some('C') => Some(Suit::Clubs),
+        Some('D') => Some(Suit::Diamonds),
+        Some('H') => Some(Suit::Hearts),
+        Some('S') => Some(Suit::Spades),
+        Some('N') => None,
+        _ => bail!("Invalid trump suit in contract {contract}"),
+    };
+    let bid =
+        chars.as_str().parse().with_context(|| format!("Invalid bid in contract {contract}"))?;
+    Contract::new(declarer, trump, bid)
+}
+
+/// Returns true if `hands` contains four 13-card hands which together hold
+/// each of the 52 cards exactly once.
+pub fn is_complete_deal(hands: &HashMap<HandIdentifier, BTreeSet<Card>>) -> bool {
+    validate_deal(hands).is_ok()
+}
+
+/// Checks that `hands` contains four 13-card hands which together hold each
+/// of the 52 cards exactly once, returning an error describing the first
+/// problem found otherwise.
+pub fn validate_deal(hands: &HashMap<HandIdentifier, BTreeSet<Card>>) -> Result<()> {
+    let mut holders = HashMap::new();
+    for hand in enum_iterator::all::<HandIdentifier>() {
+        let cards = hands.get(&hand).with_context(|| format!("No cards dealt to {hand:?}"))?;
+        for &card in cards {
+            if let Some(other) = holders.insert(card, hand) {
+                bail!("{card} is held by both {other:?} and {hand:?}");
+            }
+        }
+    }
+    for suit in enum_iterator::all::<Suit>() {
+        for rank in enum_iterator::all::<Rank>() {
+            let card = Card::new(suit, rank);
+            ensure!(holders.contains_key(&card), "{card} is not held by any hand");
+        }
+    }
+    for (hand, cards) in hands {
+        ensure!(cards.len() == 13, "{hand:?} holds {} cards, expected 13", cards.len());
+    }
+    Ok(())
+}
+
+fn build_hand(chunks: &mut ChunksExact<Card>) -> BTreeSet<Card> {
+    BTreeSet::from_iter(chunks.next().expect("Invalid deck size").iter().copied())
 }
@@ -0,0 +1,238 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for the Portable Bridge Notation (PBN) file format.
+
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use auction_phase_data::{Contract, GameConfig, Rules, MIN_BID};
+use play_phase_data::{PlayPhaseData, PriorTricks, Trick};
+use primitives::{Card, HandIdentifier, Rank, Suit};
+
+/// Suits in the order in which they appear within a PBN hand.
+const PBN_SUIT_ORDER: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+/// Parses the contents of a PBN file, returning one game for each `[Deal]` tag
+/// it contains.
+///
+/// Games are separated by blank lines. When a game has both a `[Declarer]` and
+/// a `[Contract]` tag its contract is read from them, otherwise it is created
+/// with the default [GameConfig] contract. All other tags are currently
+/// ignored.
+pub fn parse_pbn(contents: &str) -> Result<Vec<PlayPhaseData>> {
+    let mut result = vec![];
+    for section in contents.split("\n\n") {
+        let lines = section.lines().collect::<Vec<_>>();
+        let declarer = lines.iter().find_map(|line| tag_value(line, "Declarer"));
+        let contract = match (lines.iter().find_map(|line| tag_value(line, "Contract")), declarer) {
+            (Some(contract), Some(declarer)) => parse_contract(contract, declarer)?,
+            _ => GameConfig::default().contract,
+        };
+        for deal in lines.iter().filter_map(|line| tag_value(line, "Deal")) {
+            result.push(PlayPhaseData {
+                hands: parse_deal(deal)?,
+                current_trick: Trick::default(),
+                completed_tricks: vec![],
+                prior_tricks: PriorTricks::default(),
+                contract: contract.clone(),
+                rules: Rules::default(),
+                events: vec![],
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Parses the value of a PBN `[Deal]` tag, e.g. `"N:AKQ.JT9.876.5432 ..."`.
+///
+/// Returns an error unless the deal holds each of the 52 cards exactly once.
+pub fn parse_deal(deal: &str) -> Result<HashMap<HandIdentifier, BTreeSet<Card>>> {
+    let (first, hands) =
+        deal.trim().split_once(':').ok_or_else(|| anyhow!("Missing first seat in deal {deal}"))?;
+    let mut hand = parse_seat(first)?;
+    let mut result = HashMap::new();
+    let mut count = 0;
+    for cards in hands.split_whitespace() {
+        ensure!(count < 4, "Too many hands in deal {deal}");
+        result.insert(hand, parse_hand(cards)?);
+        hand = hand.next();
+        count += 1;
+    }
+    ensure!(count == 4, "Expected 4 hands in deal {deal}");
+    auction_phase_mutations::validate_deal(&result)?;
+    Ok(result)
+}
+
+/// Returns a PBN representation of this game, including the `[Deal]`,
+/// `[Declarer]` and `[Contract]` tags.
+pub fn to_pbn(data: &PlayPhaseData) -> String {
+    format!(
+        "[Deal \"{}\"]\n[Declarer \"{}\"]\n[Contract \"{}\"]\n",
+        format_deal(&data.initial_hands()),
+        seat_letter(data.declarer().primary_hand()),
+        format_contract(&data.contract)
+    )
+}
+
+/// Formats a set of hands as the value of a PBN `[Deal]` tag, starting from
+/// North.
+pub fn format_deal(hands: &HashMap<HandIdentifier, BTreeSet<Card>>) -> String {
+    let mut hand = HandIdentifier::North;
+    let mut result = Vec::new();
+    for _ in 0..4 {
+        result.push(format_hand(hands.get(&hand).cloned().unwrap_or_default()));
+        hand = hand.next();
+    }
+    format!("N:{}", result.join(" "))
+}
+
+/// Returns a compact summary of the cards currently held by every hand, one
+/// line per hand starting from North, e.g. `N AKQ.JT9.876.5432`.
+///
+/// This reveals every hand, so callers should only show it when hidden cards
+/// are meant to be visible, e.g. in a practice or debugging mode.
+pub fn preview(data: &PlayPhaseData) -> String {
+    (0..4)
+        .map(|n| {
+            let hand = HandIdentifier::North.rotate(n);
+            format!("{} {}", seat_letter(hand), format_hand(data.hand(hand).collect()))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses the values of a PBN `[Contract]` tag, e.g. `"4S"` or `"3NT"`, and
+/// the matching `[Declarer]` tag.
+///
+/// Returns an error for a passed out (`"Pass"`), doubled or out of range
+/// contract.
+pub fn parse_contract(contract: &str, declarer: &str) -> Result<Contract> {
+    let contract = contract.trim();
+    let (level, strain) =
+        contract.split_at(contract.find(|c: char| !c.is_ascii_digit()).unwrap_or(0));
+    let level = level.parse::<u32>().map_err(|_| anyhow!("Invalid contract {contract}"))?;
+    let trump = match strain {
+        "S" => Some(Suit::Spades),
+        "H" => Some(Suit::Hearts),
+        "D" => Some(Suit::Diamonds),
+        "C" => Some(Suit::Clubs),
+        "NT" => None,
+        _ => bail!("Unknown strain in contract {contract}"),
+    };
+    Contract::new(parse_seat(declarer)?.player_name(), trump, level + MIN_BID - 1)
+}
+
+/// Parses a PBN seat letter.
+pub fn parse_seat(seat: &str) -> Result<HandIdentifier> {
+    Ok(match seat.trim() {
+        "N" => HandIdentifier::North,
+        "E" => HandIdentifier::East,
+        "S" => HandIdentifier::South,
+        "W" => HandIdentifier::West,
+        _ => bail!("Unknown seat {seat}"),
+    })
+}
+
+fn parse_hand(hand: &str) -> Result<BTreeSet<Card>> {
+    let suits = hand.split('.').collect::<Vec<_>>();
+    ensure!(suits.len() == 4, "Expected 4 suits in hand {hand}");
+    let mut result = BTreeSet::new();
+    for (suit, ranks) in PBN_SUIT_ORDER.iter().zip(suits) {
+        for rank in ranks.chars().filter(|c| *c != '-') {
+            result.insert(Card::new(*suit, parse_rank(rank)?));
+        }
+    }
+    Ok(result)
+}
+
+fn format_hand(hand: BTreeSet<Card>) -> String {
+    PBN_SUIT_ORDER
+        .iter()
+        .map(|suit| {
+            let mut ranks =
+                hand.iter().filter(|c| c.suit == *suit).map(|c| c.rank).collect::<Vec<_>>();
+            ranks.sort_by(|a, b| b.cmp(a));
+            ranks.into_iter().map(rank_letter).collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn format_contract(contract: &Contract) -> String {
+    let strain = match contract.trump {
+        Some(Suit::Spades) => "S",
+        Some(Suit::Hearts) => "H",
+        Some(Suit::Diamonds) => "D",
+        Some(Suit::Clubs) => "C",
+        None => "NT",
+    };
+    format!("{}{strain}", contract.bid.saturating_sub(MIN_BID - 1))
+}
+
+fn seat_letter(hand: HandIdentifier) -> char {
+    match hand {
+        HandIdentifier::North => 'N',
+        HandIdentifier::East => 'E',
+        HandIdentifier::South => 'S',
+        HandIdentifier::West => 'W',
+    }
+}
+
+fn rank_letter(rank: Rank) -> char {
+    match rank {
+        Rank::Two => '2',
+        Rank::Three => '3',
+        Rank::Four => '4',
+        Rank::Five => '5',
+        Rank::Six => '6',
+        Rank::Seven => '7',
+        Rank::Eight => '8',
+        Rank::Nine => '9',
+        Rank::Ten => 'T',
+        Rank::Jack => 'J',
+        Rank::Queen => 'Q',
+        Rank::King => 'K',
+        Rank::Ace => 'A',
+    }
+}
+
+fn parse_rank(rank: char) -> Result<Rank> {
+    Ok(match rank.to_ascii_uppercase() {
+        '2' => Rank::Two,
+        '3' => Rank::Three,
+        '4' => Rank::Four,
+        '5' => Rank::Five,
+        '6' => Rank::Six,
+        '7' => Rank::Seven,
+        '8' => Rank::Eight,
+        '9' => Rank::Nine,
+        'T' => Rank::Ten,
+        'J' => Rank::Jack,
+        'Q' => Rank::Queen,
+        'K' => Rank::King,
+        'A' => Rank::Ace,
+        _ => bail!("Unknown rank {rank}"),
+    })
+}
+
+/// Returns the value of `line` if it is a PBN tag with the given name.
+fn tag_value<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let rest = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (tag, value) = rest.split_once(char::is_whitespace)?;
+    if tag != name {
+        return None;
+    }
+    value.trim().strip_prefix('"')?.strip_suffix('"')
+}
@@ -13,5 +13,10 @@
 // limitations under the License.
 
 pub mod play_phase_actions;
+pub mod play_phase_analysis;
+pub mod play_phase_coach;
+pub mod play_phase_debug;
 pub mod play_phase_flags;
 pub mod play_phase_queries;
+pub mod play_phase_result;
+pub mod play_phase_review;
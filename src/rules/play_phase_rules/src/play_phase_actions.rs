@@ -12,21 +12,90 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use play_phase_data::{PlayPhaseAction, PlayPhaseData, PlayedCard};
+use anyhow::{ensure, Result};
+use play_phase_data::{CompletedTrick, GameEvent, PlayPhaseAction, PlayPhaseData, PlayedCard};
 use primitives::{Card, HandIdentifier, PlayerName};
 
-use crate::play_phase_flags;
+use crate::{play_phase_analysis, play_phase_flags, play_phase_queries};
 
 pub fn handle_action(data: &mut PlayPhaseData, action: PlayPhaseAction) {
     match action {
         PlayPhaseAction::PlayCard(player, hand, card) => play_card(data, player, hand, card),
+        PlayPhaseAction::ContinueToNextTrick => continue_to_next_trick(data),
     }
 }
 
+/// Applies `action` if it is currently legal, otherwise returns an error
+/// describing why it is not and leaves `data` unchanged.
+///
+/// Use this instead of [handle_action] for actions which come from untrusted
+/// input, e.g. deserialized data.
+pub fn try_handle_action(data: &mut PlayPhaseData, action: PlayPhaseAction) -> Result<()> {
+    match action {
+        PlayPhaseAction::PlayCard(_, hand, card) => {
+            ensure!(
+                data.hands.get(&hand).is_some_and(|cards| cards.contains(&card)),
+                "{hand:?} does not hold {card}"
+            );
+            ensure!(play_phase_queries::next_to_play(data) == hand, "It is not {hand:?}'s turn");
+            ensure!(play_phase_flags::can_play_card(data, hand, card), "{hand:?} must follow suit");
+        }
+        PlayPhaseAction::ContinueToNextTrick => {
+            ensure!(
+                play_phase_flags::can_continue_to_next_trick(data),
+                "No trick to continue from"
+            );
+        }
+    }
+    handle_action(data, action);
+    Ok(())
+}
+
 /// Plays the indicated [Card] from the hand identified by [HandIdentifier] if
 /// it is currently legal to do so.
+///
+/// If the current trick is already complete, it is moved to the list of
+/// completed tricks before the card is played.
 fn play_card(data: &mut PlayPhaseData, _: PlayerName, hand: HandIdentifier, card: Card) {
     assert!(play_phase_flags::can_play_card(data, hand, card), "Cannot play {card}");
+    if data.current_trick.cards.len() == 4 {
+        complete_trick(data);
+    }
     data.hands.get_mut(&hand).unwrap().remove(&card);
     data.current_trick.cards.push(PlayedCard { played_by: hand, card });
+    record_events(data, hand, card);
+}
+
+/// Appends the [GameEvent]s caused by `hand` having just played `card`.
+fn record_events(data: &mut PlayPhaseData, hand: HandIdentifier, card: Card) {
+    let lead = data.current_trick.lead_card().map(|c| c.suit);
+    if let Some(lead) = lead.filter(|&lead| lead != card.suit && Some(card.suit) == data.trump()) {
+        data.events.push(GameEvent::Ruffed(hand, lead));
+    }
+    if data.current_trick.cards.len() == 4 {
+        let winner = play_phase_queries::trick_winner(&data.current_trick, data.trump());
+        data.events.push(GameEvent::TrickWon(winner));
+    }
+    if data.is_game_over() {
+        let declarer_tricks = play_phase_analysis::declarer_tricks(data);
+        data.events.push(if data.tricks_still_needed(declarer_tricks) == 0 {
+            GameEvent::ContractMade
+        } else {
+            GameEvent::ContractDefeated
+        });
+    }
+}
+
+/// Completes the current trick ahead of the next lead.
+fn continue_to_next_trick(data: &mut PlayPhaseData) {
+    assert!(play_phase_flags::can_continue_to_next_trick(data), "Cannot continue");
+    complete_trick(data);
+}
+
+/// Moves the current trick to the list of completed tricks, recording its
+/// winner.
+fn complete_trick(data: &mut PlayPhaseData) {
+    let trick = std::mem::take(&mut data.current_trick);
+    let winner = play_phase_queries::trick_winner(&trick, data.trump());
+    data.completed_tricks.push(CompletedTrick { trick, winner });
 }
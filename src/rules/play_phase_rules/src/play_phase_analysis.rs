@@ -0,0 +1,436 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeSet, HashMap};
+
+use auction_phase_data::MIN_BID;
+use play_phase_data::{PlayPhaseAction, PlayPhaseData};
+use primitives::{Card, HandIdentifier, PlayerName, Rank, Suit};
+
+use crate::{play_phase_actions, play_phase_queries};
+
+/// Returns the winner of each trick which has been completed so far, in
+/// order, including the final trick of a finished game.
+pub fn trick_winners(data: &PlayPhaseData) -> Vec<HandIdentifier> {
+    let mut result =
+        data.completed_tricks.iter().map(|completed| completed.winner).collect::<Vec<_>>();
+    if data.current_trick.cards.len() == 4 {
+        result.push(play_phase_queries::trick_winner(&data.current_trick, data.trump()));
+    }
+    result
+}
+
+/// Returns the number of tricks won by the declaring side so far, including
+/// its prior tricks and the final trick of a finished game.
+pub fn declarer_tricks(data: &PlayPhaseData) -> usize {
+    let played = trick_winners(data)
+        .into_iter()
+        .filter(|winner| winner.player_name() == data.declarer())
+        .count();
+    data.prior_tricks.declarer + played
+}
+
+/// Returns the index of the trick on which the outcome of the contract became
+/// certain, i.e. the trick on which the declarer won enough tricks to make the
+/// contract or the defenders won enough tricks to defeat it.
+///
+/// Prior tricks count towards the outcome and are included in the index, so
+/// the first played trick has the index `data.prior_tricks.total()`.
+///
+/// Returns None if the outcome has not been decided yet.
+pub fn decisive_trick(data: &PlayPhaseData) -> Option<usize> {
+    let prior = data.prior_tricks;
+    let dealt = data.initial_hands().values().map(|hand| hand.len()).max().unwrap_or_default();
+    let total = prior.total() + dealt;
+    let needed = data.contract.bid as usize;
+    let (mut declarer, mut defenders) = (prior.declarer, prior.defenders);
+    if prior.total() > 0 && (declarer >= needed || defenders > total.saturating_sub(needed)) {
+        return Some(prior.total() - 1);
+    }
+    for (index, winner) in trick_winners(data).into_iter().enumerate() {
+        let index = prior.total() + index;
+        if winner.player_name() == data.declarer() {
+            declarer += 1;
+        } else {
+            defenders += 1;
+        }
+        if declarer >= needed || defenders > total.saturating_sub(needed) {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Number of tricks in a game played with a full deck.
+pub const TRICKS_PER_GAME: usize = 13;
+
+/// Returns the number of distinct ways the rest of the game can be played out
+/// from `data` under the rules for legal plays.
+///
+/// This visits every reachable position, so it is only practical for games
+/// with a small number of cards remaining.
+pub fn count_leaf_nodes(data: &PlayPhaseData) -> u64 {
+    if data.is_game_over() {
+        return 1;
+    }
+    let hand = play_phase_queries::next_to_play(data);
+    play_phase_queries::legal_plays(data, hand)
+        .into_iter()
+        .map(|card| {
+            let mut next = data.clone();
+            play_phase_actions::handle_action(
+                &mut next,
+                PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+            );
+            count_leaf_nodes(&next)
+        })
+        .sum()
+}
+
+/// Returns the number of tricks won by ruffing, i.e. won with a trump when a
+/// different suit was led.
+///
+/// Includes the final trick of a finished game. Always 0 in a no trump
+/// contract.
+pub fn ruff_count(data: &PlayPhaseData) -> usize {
+    let Some(trump) = data.trump() else {
+        return 0;
+    };
+    let current = Some(&data.current_trick).filter(|trick| trick.cards.len() == 4);
+    data.completed_tricks
+        .iter()
+        .map(|completed| &completed.trick)
+        .chain(current)
+        .filter(|trick| {
+            let lead = trick.lead_card().map(|card| card.suit);
+            let winning = play_phase_queries::trick_result(trick, Some(trump));
+            lead != Some(trump) && winning.is_some_and(|(_, card)| card.suit == trump)
+        })
+        .count()
+}
+
+/// Returns the probability, from the declaring side's point of view, that the
+/// missing `honor` of `suit` is held by the defender who plays immediately
+/// before the declaring hand holding the side's highest card of the suit, i.e.
+/// that a finesse against it would succeed.
+///
+/// Every distribution of the defenders' unseen cards which matches their hand
+/// sizes is treated as equally likely, except that a defender who has already
+/// failed to follow to `suit` cannot hold the honor. Among those deals the
+/// honor sits in a given defender's hand in proportion to the number of cards
+/// that defender holds.
+///
+/// Returns 0.0 if the honor is not held by a defender or the declaring side
+/// holds no cards of the suit.
+pub fn honor_onside_probability(data: &PlayPhaseData, suit: Suit, honor: Rank) -> f64 {
+    let honor = Card::new(suit, honor);
+    let declarer = data.declarer().primary_hand();
+    let Some(tenace) = [declarer, declarer.partner()]
+        .into_iter()
+        .filter_map(|hand| {
+            play_phase_queries::highest_of_suit(data, hand, suit).map(|card| (card, hand))
+        })
+        .max()
+        .map(|(_, hand)| hand)
+    else {
+        return 0.0;
+    };
+    let onside = tenace.rotate(3);
+    let offside = onside.partner();
+    if !data.hand(onside).chain(data.hand(offside)).any(|card| card == honor) {
+        return 0.0;
+    }
+
+    let places = |hand: HandIdentifier| {
+        if has_shown_void(data, hand, suit) {
+            0.0
+        } else {
+            data.hand(hand).count() as f64
+        }
+    };
+    places(onside) / (places(onside) + places(offside))
+}
+
+/// Returns true if the indicated hand has failed to follow to a trick on which
+/// `suit` was led.
+fn has_shown_void(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> bool {
+    data.completed_tricks
+        .iter()
+        .map(|completed| &completed.trick)
+        .chain(Some(&data.current_trick))
+        .any(|trick| {
+            trick.lead_card().is_some_and(|lead| lead.suit == suit)
+                && trick
+                    .cards
+                    .iter()
+                    .any(|played| played.played_by == hand && played.card.suit != suit)
+        })
+}
+
+/// Returns true if the declaring side is on lead in a simple squeeze position.
+///
+/// This is a heuristic based on the cards currently held: it looks for a
+/// defender who is the only player guarding two different suits against the
+/// declaring side's threats, who has no idle cards, and who is void in a
+/// third suit in which the declaring side has a winner to cash that neither
+/// defender can ruff. A card is idle if it is in a suit the defender does not
+/// need to guard, or if the defender holds more cards of a guarded suit than
+/// are needed to keep pace with the declaring side's higher cards in it.
+/// Cashing the winner then forces the defender to discard a guard.
+pub fn is_squeeze_position(data: &PlayPhaseData) -> bool {
+    let leader = play_phase_queries::next_to_play(data);
+    let on_lead = data.current_trick.cards.is_empty() || data.current_trick.cards.len() == 4;
+    if !on_lead || leader.player_name() != data.declarer() {
+        return false;
+    }
+
+    let declarer = data.declarer().primary_hand();
+    let defenders = [declarer.next(), declarer.next().partner()];
+    defenders.into_iter().any(|defender| {
+        let guards = enum_iterator::all::<Suit>()
+            .filter_map(|suit| guard_length(data, defender, suit).map(|length| (suit, length)))
+            .collect::<HashMap<_, _>>();
+        let no_idle_cards = enum_iterator::all::<Suit>().all(|suit| {
+            let held = data.hand(defender).filter(|card| card.suit == suit).count();
+            held <= guards.get(&suit).copied().unwrap_or_default()
+        });
+        guards.len() >= 2
+            && no_idle_cards
+            && enum_iterator::all::<Suit>().any(|suit| {
+                !guards.contains_key(&suit)
+                    && data.hand(defender).all(|card| card.suit != suit)
+                    && has_cashable_winner(data, suit)
+                    && !defenders_can_ruff(data, suit)
+            })
+    })
+}
+
+/// Returns the number of cards of `suit` the indicated defender must keep to
+/// stop the declaring side's threats in the suit on their own, or None if
+/// they are not the sole guard of the suit.
+///
+/// A threat is a card of the declaring side which the defender's highest card
+/// of the suit beats and their partner's does not. The defender must keep one
+/// card for each of the declaring side's cards ranked above their highest
+/// card, plus that card itself.
+fn guard_length(data: &PlayPhaseData, defender: HandIdentifier, suit: Suit) -> Option<usize> {
+    let top = |hand: HandIdentifier| data.hand(hand).filter(|card| card.suit == suit).max();
+    let guard = top(defender)?;
+    let partner = top(defender.partner());
+    let declaring = data.combined_hand(data.declarer());
+    let has_threat = declaring
+        .iter()
+        .any(|&card| card.suit == suit && card < guard && partner.is_none_or(|p| card > p));
+    if !has_threat {
+        return None;
+    }
+    Some(declaring.iter().filter(|&&card| card.suit == suit && card > guard).count() + 1)
+}
+
+/// Returns true if the declaring side holds a card of `suit` ranked above
+/// every card of the suit held by the defenders.
+fn has_cashable_winner(data: &PlayPhaseData, suit: Suit) -> bool {
+    let declarer = data.declarer().primary_hand();
+    let Some(top) =
+        data.combined_hand(data.declarer()).into_iter().filter(|card| card.suit == suit).max()
+    else {
+        return false;
+    };
+    data.hand(declarer.next())
+        .chain(data.hand(declarer.next().partner()))
+        .all(|card| card.suit != suit || card < top)
+}
+
+/// Returns true if `suit` is not trump and either defender holds a trump, so
+/// a winner led in the suit could be ruffed.
+fn defenders_can_ruff(data: &PlayPhaseData, suit: Suit) -> bool {
+    let Some(trump) = data.trump().filter(|&trump| trump != suit) else {
+        return false;
+    };
+    let declarer = data.declarer().primary_hand();
+    data.hand(declarer.next())
+        .chain(data.hand(declarer.next().partner()))
+        .any(|card| card.suit == trump)
+}
+
+/// Statistics describing the hands as they were originally dealt, computed
+/// once for analytics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DealSummary {
+    /// High card points dealt to each hand.
+    pub hcp: HashMap<HandIdentifier, usize>,
+    /// Number of cards of each suit dealt to each hand.
+    pub shapes: HashMap<HandIdentifier, HashMap<Suit, usize>>,
+    /// For each partnership, the suit in which its two hands were dealt the
+    /// most cards between them and that number of cards. Ties are broken in
+    /// favor of the higher-ranking suit.
+    pub longest_fit: HashMap<PlayerName, (Suit, usize)>,
+}
+
+impl DealSummary {
+    /// Summarizes the hands dealt in `data`, including cards which have since
+    /// been played.
+    pub fn analyze(data: &PlayPhaseData) -> Self {
+        let initial = data.initial_hands();
+        let hcp = initial
+            .iter()
+            .map(|(&hand, cards)| {
+                (hand, cards.iter().map(|card| card.rank.high_card_points()).sum())
+            })
+            .collect();
+        let shapes = initial
+            .iter()
+            .map(|(&hand, cards)| {
+                let shape = enum_iterator::all::<Suit>()
+                    .map(|suit| (suit, cards.iter().filter(|card| card.suit == suit).count()))
+                    .collect::<HashMap<_, _>>();
+                (hand, shape)
+            })
+            .collect::<HashMap<_, _>>();
+        let longest_fit = enum_iterator::all::<PlayerName>()
+            .map(|side| {
+                let primary = side.primary_hand();
+                let fit = enum_iterator::all::<Suit>()
+                    .map(|suit| {
+                        let length = |hand| shapes.get(&hand).map_or(0, |shape| shape[&suit]);
+                        (suit, length(primary) + length(primary.partner()))
+                    })
+                    .max_by_key(|&(suit, length)| (length, suit))
+                    .unwrap();
+                (side, fit)
+            })
+            .collect();
+        Self { hcp, shapes, longest_fit }
+    }
+}
+
+/// Returns the number of quick tricks in `hand`, a measure of defensive
+/// strength used alongside high card points when evaluating a hand for
+/// bidding.
+///
+/// Each suit is scored on its top honors and the results are summed: AK is
+/// worth 2, AQ 1.5, A alone 1, KQ 1, and a King with at least one other card
+/// 0.5.
+pub fn quick_tricks(hand: &BTreeSet<Card>) -> f64 {
+    enum_iterator::all::<Suit>()
+        .map(|suit| {
+            let holds = |rank| hand.contains(&Card::new(suit, rank));
+            let length = hand.iter().filter(|card| card.suit == suit).count();
+            match (holds(Rank::Ace), holds(Rank::King), holds(Rank::Queen)) {
+                (true, true, _) => 2.0,
+                (true, false, true) => 1.5,
+                (true, false, false) => 1.0,
+                (false, true, true) => 1.0,
+                (false, true, false) if length >= 2 => 0.5,
+                _ => 0.0,
+            }
+        })
+        .sum()
+}
+
+/// Returns an estimate of the number of tricks `hand` can take as declarer
+/// with `trump` as the trump suit, or in no trump if it is None.
+///
+/// Each suit is scored separately: an Ace is a winner, a King is a winner with
+/// at least one other card of the suit and a Queen with at least two. Every
+/// trump beyond the third and every card of another suit beyond the fourth
+/// counts as a long-suit winner. A suit never counts for more tricks than the
+/// cards held in it.
+pub fn playing_tricks(hand: &BTreeSet<Card>, trump: Option<Suit>) -> usize {
+    enum_iterator::all::<Suit>()
+        .map(|suit| {
+            let holds = |rank| hand.contains(&Card::new(suit, rank));
+            let length = hand.iter().filter(|card| card.suit == suit).count();
+            let honors = [(Rank::Ace, 1), (Rank::King, 2), (Rank::Queen, 3)]
+                .into_iter()
+                .filter(|&(rank, needed)| holds(rank) && length >= needed)
+                .count();
+            let long_after = if Some(suit) == trump { 3 } else { 4 };
+            (honors + length.saturating_sub(long_after)).min(length)
+        })
+        .sum()
+}
+
+/// Smallest combined high card point total for a partnership to expect each
+/// number of tricks, from 8 up to all 13.
+const POINTS_FOR_TRICKS: [(usize, usize); 6] =
+    [(23, 8), (25, 9), (28, 10), (31, 11), (33, 12), (37, 13)];
+
+/// Returns the number of tricks a partnership holding `combined_points` high
+/// card points between its two hands can expect to take as declarer, e.g. 9
+/// tricks for 26 points. Totals below 23 points map to [MIN_BID].
+pub fn suggested_level(combined_points: usize) -> usize {
+    POINTS_FOR_TRICKS
+        .iter()
+        .rev()
+        .find(|&&(points, _)| combined_points >= points)
+        .map_or(MIN_BID as usize, |&(_, tricks)| tricks)
+}
+
+/// Returns true if the indicated hand is on lead and every card it could lead
+/// loses the current trick to the opponents, assuming all four hands then play
+/// the rest of the trick with full knowledge of each other's cards.
+///
+/// This only looks ahead one trick, so it does not consider whether a
+/// losing lead costs a trick the opponents would not otherwise have won.
+pub fn is_endplayed(data: &PlayPhaseData, hand: HandIdentifier) -> bool {
+    let on_lead = data.current_trick.cards.is_empty() || data.current_trick.cards.len() == 4;
+    if !on_lead || data.is_game_over() || play_phase_queries::next_to_play(data) != hand {
+        return false;
+    }
+    play_phase_queries::legal_plays(data, hand).into_iter().all(|card| {
+        let mut next = data.clone();
+        play_phase_actions::handle_action(
+            &mut next,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+        !side_wins_trick(&next, hand.player_name())
+    })
+}
+
+/// Returns true if `side` wins the current trick when each remaining card is
+/// played optimally by the hand playing it.
+fn side_wins_trick(data: &PlayPhaseData, side: PlayerName) -> bool {
+    if data.current_trick.cards.len() == 4 {
+        return play_phase_queries::trick_winner(&data.current_trick, data.trump()).player_name()
+            == side;
+    }
+    let hand = play_phase_queries::next_to_play(data);
+    let mut outcomes = play_phase_queries::legal_plays(data, hand).into_iter().map(|card| {
+        let mut next = data.clone();
+        play_phase_actions::handle_action(
+            &mut next,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+        side_wins_trick(&next, side)
+    });
+    if hand.player_name() == side {
+        outcomes.any(|wins| wins)
+    } else {
+        outcomes.all(|wins| wins)
+    }
+}
+
+/// Returns the suit the defenders are most likely to attack, estimated as the
+/// suit in which they hold the most cards between them from the declarer's
+/// point of view. Ties are broken in favor of the higher-ranking suit.
+///
+/// Returns None if the defenders can hold no unseen cards.
+pub fn likely_opponent_suit(data: &PlayPhaseData) -> Option<Suit> {
+    play_phase_queries::unseen_suit_counts(data)
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(suit, count)| (count, suit))
+        .map(|(suit, _)| suit)
+}
@@ -0,0 +1,79 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseData;
+use primitives::{Card, HandIdentifier, Rank, Suit};
+
+use crate::play_phase_queries;
+
+/// Recommends a card for the indicated hand to make the opening lead with.
+///
+/// Against no trump this is the fourth-highest card of the longest suit (or
+/// the top card if no suit has four cards). Against a trump contract a
+/// singleton in a side suit is preferred if the hand holds a trump to ruff
+/// with, followed by the top of a sequence of touching honors, and otherwise
+/// the no trump rule applied to the side suits.
+///
+/// Panics if the hand is empty.
+pub fn recommend_opening_lead(data: &PlayPhaseData, hand: HandIdentifier) -> Card {
+    let suits = |include_trump: bool| {
+        enum_iterator::all::<Suit>()
+            .filter(move |&suit| include_trump || Some(suit) != data.trump())
+            .map(|suit| play_phase_queries::cards_of_suit_desc(data, hand, suit))
+            .filter(|cards| !cards.is_empty())
+            .collect::<Vec<_>>()
+    };
+
+    if let Some(trump) = data.trump() {
+        let side_suits = suits(false);
+        let holds_trump = data.hand(hand).any(|card| card.suit == trump);
+        if let Some(singleton) = side_suits.iter().find(|cards| cards.len() == 1) {
+            if holds_trump {
+                return singleton[0];
+            }
+        }
+        if let Some(top) =
+            side_suits.iter().filter_map(|cards| sequence_top(cards)).max_by_key(|card| card.rank)
+        {
+            return top;
+        }
+        if !side_suits.is_empty() {
+            return fourth_highest_of_longest(&side_suits);
+        }
+    }
+
+    fourth_highest_of_longest(&suits(true))
+}
+
+/// Returns the higher card of the highest pair of touching cards ranked Ten or
+/// above in `cards`, which must be sorted in descending order.
+fn sequence_top(cards: &[Card]) -> Option<Card> {
+    cards
+        .windows(2)
+        .find(|pair| pair[1].rank >= Rank::Ten && pair[1].higher_in_suit().next() == Some(pair[0]))
+        .map(|pair| pair[0])
+}
+
+/// Returns the fourth-highest card of the longest suit in `suits`, or its top
+/// card if it has fewer than four cards. Ties are broken in favor of the suit
+/// with more high card points.
+fn fourth_highest_of_longest(suits: &[Vec<Card>]) -> Card {
+    let longest = suits
+        .iter()
+        .max_by_key(|cards| {
+            (cards.len(), cards.iter().map(|card| card.rank.high_card_points()).sum::<usize>())
+        })
+        .expect("Hand is empty");
+    longest.get(3).copied().unwrap_or(longest[0])
+}
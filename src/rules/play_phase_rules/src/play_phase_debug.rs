@@ -0,0 +1,53 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseData;
+use primitives::{Card, HandIdentifier};
+
+/// Width of the left-hand column holding West's cards.
+const COLUMN_WIDTH: usize = 40;
+
+/// Renders the current state of the game as text for debugging.
+///
+/// The four hands are laid out by compass position, North at the top and South
+/// at the bottom, with the cards of the current trick in the center.
+pub fn ascii_board(data: &PlayPhaseData) -> String {
+    let hand = |identifier: HandIdentifier| {
+        format!("{identifier:?}: {}", join(data.sorted_hand(identifier)))
+    };
+    let trick = data
+        .current_trick
+        .cards
+        .iter()
+        .map(|played| format!("{} ({:?})", played.card, played.played_by))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let indent = " ".repeat(COLUMN_WIDTH / 2);
+    [
+        format!("{indent}{}", hand(HandIdentifier::North)),
+        format!(
+            "{:<width$}{}",
+            hand(HandIdentifier::West),
+            hand(HandIdentifier::East),
+            width = COLUMN_WIDTH
+        ),
+        format!("{indent}Trick: {trick}"),
+        format!("{indent}{}", hand(HandIdentifier::South)),
+    ]
+    .join("\n")
+}
+
+fn join(cards: Vec<Card>) -> String {
+    cards.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
+}
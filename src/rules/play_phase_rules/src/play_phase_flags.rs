@@ -17,9 +17,28 @@ use primitives::{Card, HandIdentifier};
 
 use crate::play_phase_queries;
 
+/// Returns true if the hand identified by [HandIdentifier] can currently play
+/// the indicated [Card].
+///
+/// Players must follow the suit led to the current trick if they are able to.
 pub fn can_play_card(data: &PlayPhaseData, hand: HandIdentifier, card: Card) -> bool {
     if play_phase_queries::next_to_play(data) != hand {
         return false;
     }
-    data.hands.get(&hand).unwrap().contains(&card)
+    if !data.hands.get(&hand).unwrap().contains(&card) {
+        return false;
+    }
+    match play_phase_queries::lead_suit(data) {
+        Some(suit) if card.suit != suit => data.hand(hand).all(|c| c.suit != suit),
+        _ => true,
+    }
+}
+
+/// Returns true if the current trick is complete and can be moved to the list
+/// of completed tricks.
+///
+/// The final trick of the game is never moved, so that it remains visible once
+/// play has ended.
+pub fn can_continue_to_next_trick(data: &PlayPhaseData) -> bool {
+    data.current_trick.cards.len() == 4 && !data.is_game_over()
 }
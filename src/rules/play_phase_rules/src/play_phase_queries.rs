@@ -12,28 +12,446 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use play_phase_data::{PlayPhaseData, Trick};
-use primitives::HandIdentifier;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+
+use play_phase_data::{PlayPhaseData, SuitPlayCount, Trick};
+use primitives::{Card, HandIdentifier, PlayerName, Rank, Suit};
+
+use crate::play_phase_flags;
 
 /// Returns the [HandIdentifier] to next play a card during a round.
 pub fn next_to_play(data: &PlayPhaseData) -> HandIdentifier {
     match data.current_trick.cards.len() {
         0 => {
             if let Some(last) = data.completed_tricks.last() {
-                trick_winner(&last.trick)
+                last.winner
             } else {
-                data.contract.declarer.primary_hand()
+                data.opening_leader()
             }
         }
         1..=3 => data.current_trick.cards.last().unwrap().played_by.next(),
-        4 => trick_winner(&data.current_trick),
+        4 => trick_winner(&data.current_trick, data.trump()),
         _ => panic!("Invalid trick size"),
     }
 }
 
-/// Returns the [HandIdentifier] which won a given trick
-pub fn trick_winner(trick: &Trick) -> HandIdentifier {
-    let mut cards = trick.cards.clone();
-    cards.sort_by_key(|played| played.card);
-    cards.last().unwrap().played_by
+/// Returns the sequence of hands which will act to finish the current trick.
+///
+/// If the current trick has not started or is already complete, returns all
+/// four hands in turn order, starting with the hand which will lead the next
+/// trick.
+pub fn upcoming_turn_order(data: &PlayPhaseData) -> Vec<HandIdentifier> {
+    let remaining = match data.current_trick.cards.len() {
+        4 => 4,
+        n => 4 - n,
+    };
+    let next = next_to_play(data);
+    (0..remaining).map(|n| next.rotate(n)).collect()
+}
+
+/// Returns the [HandIdentifier] which won a given trick with the indicated
+/// trump suit.
+///
+/// For a trick which is still in progress, returns the hand currently winning
+/// it. Panics if no cards have been played to the trick.
+pub fn trick_winner(trick: &Trick, trump: Option<Suit>) -> HandIdentifier {
+    winning_play(trick, trump).expect("Trick has no cards").0
+}
+
+/// Returns the hand which won a completed trick and the card it won with, or
+/// None if fewer than four cards have been played to the trick.
+pub fn trick_result(trick: &Trick, trump: Option<Suit>) -> Option<(HandIdentifier, Card)> {
+    if trick.cards.len() != 4 {
+        return None;
+    }
+    winning_play(trick, trump)
+}
+
+/// Returns the hand and card currently winning `trick`, or None if no cards
+/// have been played to it.
+fn winning_play(trick: &Trick, trump: Option<Suit>) -> Option<(HandIdentifier, Card)> {
+    let lead = trick.lead_card()?.suit;
+    trick
+        .cards
+        .iter()
+        .max_by(|a, b| compare_card_power(trump, lead, a.card, b.card))
+        .map(|played| (played.played_by, played.card))
+}
+
+/// Compares two cards played to a trick in which `lead` was the suit led.
+///
+/// Trumps beat all other cards, followed by cards of the suit led, with ties
+/// broken by rank. Cards which are neither trumps nor of the suit led cannot
+/// win the trick.
+pub fn compare_card_power(trump: Option<Suit>, lead: Suit, a: Card, b: Card) -> Ordering {
+    let power = |card: Card| (Some(card.suit) == trump, card.suit == lead, card.rank);
+    power(a).cmp(&power(b))
+}
+
+/// Returns the cards the declarer cannot see which would beat `card` if it
+/// were led to a new trick, in sorted order.
+///
+/// Like [unseen_suit_counts], this considers every card not held by the
+/// declaring side and not yet played, since these are the cards the defenders
+/// may still hold.
+pub fn cards_that_beat(data: &PlayPhaseData, card: Card) -> Vec<Card> {
+    let seen = data
+        .combined_hand(data.declarer())
+        .into_iter()
+        .chain(data.played_cards().map(|played| played.card))
+        .collect::<Vec<_>>();
+    enum_iterator::all::<Suit>()
+        .flat_map(|suit| enum_iterator::all::<Rank>().map(move |rank| Card::new(suit, rank)))
+        .filter(|c| !seen.contains(c))
+        .filter(|&c| compare_card_power(data.trump(), card.suit, c, card) == Ordering::Greater)
+        .collect()
+}
+
+/// Returns the suit which must be followed in the current trick, if any.
+///
+/// Returns None when the next card played will lead a new trick.
+pub fn lead_suit(data: &PlayPhaseData) -> Option<Suit> {
+    match data.current_trick.cards.len() {
+        1..=3 => data.current_trick.lead_card().map(|card| card.suit),
+        _ => None,
+    }
+}
+
+/// Returns the cards which the indicated hand can currently play, in sorted
+/// order.
+pub fn legal_plays(data: &PlayPhaseData, hand: HandIdentifier) -> Vec<Card> {
+    data.hand(hand).filter(|&card| play_phase_flags::can_play_card(data, hand, card)).collect()
+}
+
+/// Returns the card the next player to act must play, if they have exactly
+/// one legal play available.
+///
+/// Always returns None once the game is over, so callers which play forced
+/// cards automatically stop cleanly at the end of the game.
+pub fn forced_play(data: &PlayPhaseData) -> Option<(HandIdentifier, Card)> {
+    if data.is_game_over() {
+        return None;
+    }
+    let hand = next_to_play(data);
+    match legal_plays(data, hand).as_slice() {
+        [card] => Some((hand, *card)),
+        _ => None,
+    }
+}
+
+/// Returns true if the indicated hand could win a trick led in `suit` by
+/// playing a trump, i.e. there is a trump suit other than `suit`, the hand is
+/// void in `suit`, and the hand holds at least one trump.
+pub fn can_ruff(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> bool {
+    let Some(trump) = data.trump() else {
+        return false;
+    };
+    trump != suit
+        && data.hand(hand).all(|card| card.suit != suit)
+        && data.hand(hand).any(|card| card.suit == trump)
+}
+
+/// Returns the highest-ranked card of `suit` in the indicated hand, or None if
+/// the hand is void in that suit.
+pub fn highest_of_suit(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> Option<Card> {
+    data.hand(hand).filter(|card| card.suit == suit).max()
+}
+
+/// Returns the lowest-ranked card of `suit` in the indicated hand, or None if
+/// the hand is void in that suit.
+pub fn lowest_of_suit(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> Option<Card> {
+    data.hand(hand).filter(|card| card.suit == suit).min()
+}
+
+/// Returns the lowest card the indicated hand could legally play which would
+/// beat every card played so far to the current trick.
+///
+/// Returns None if no card has been led to the current trick, if the hand is
+/// not next to play, or if none of its legal plays would win.
+pub fn minimum_winning_card(data: &PlayPhaseData, hand: HandIdentifier) -> Option<Card> {
+    let lead = lead_suit(data)?;
+    let trump = data.trump();
+    let winning = data
+        .current_trick
+        .cards
+        .iter()
+        .map(|played| played.card)
+        .max_by(|&a, &b| compare_card_power(trump, lead, a, b))?;
+    legal_plays(data, hand)
+        .into_iter()
+        .filter(|&card| compare_card_power(trump, lead, card, winning) == Ordering::Greater)
+        .min_by(|&a, &b| compare_card_power(trump, lead, a, b))
+}
+
+/// Returns the number of cards of each suit which have been played so far,
+/// including cards in the current trick, and the number still outstanding.
+///
+/// Every suit is present in the result.
+pub fn suit_play_counts(data: &PlayPhaseData) -> HashMap<Suit, SuitPlayCount> {
+    let mut result = enum_iterator::all::<Suit>()
+        .map(|suit| (suit, SuitPlayCount::default()))
+        .collect::<HashMap<_, _>>();
+    for played in data.played_cards() {
+        result.get_mut(&played.card.suit).unwrap().played += 1;
+    }
+    for card in data.hands.values().flatten() {
+        result.get_mut(&card.suit).unwrap().outstanding += 1;
+    }
+    result
+}
+
+/// Returns cards which `hand` could lead toward a simple finesse, in sorted
+/// order.
+///
+/// A suit qualifies when `hand` is on lead, its partner holds the Ace and
+/// Queen of the suit, and the King is still held by an opponent. The lowest
+/// card `hand` holds in each qualifying suit is returned.
+pub fn finesse_candidates(data: &PlayPhaseData, hand: HandIdentifier) -> Vec<Card> {
+    if lead_suit(data).is_some() || next_to_play(data) != hand {
+        return vec![];
+    }
+
+    let partner = hand.partner();
+    let mut result = enum_iterator::all::<Suit>()
+        .filter(|&suit| {
+            let partner_holds = |rank| data.holder(Card::new(suit, rank)) == Some(partner);
+            partner_holds(Rank::Ace)
+                && partner_holds(Rank::Queen)
+                && data
+                    .holder(Card::new(suit, Rank::King))
+                    .is_some_and(|holder| holder.player_name() != hand.player_name())
+        })
+        .filter_map(|suit| lowest_of_suit(data, hand, suit))
+        .collect::<Vec<_>>();
+    result.sort();
+    result
+}
+
+/// Returns the cards of `suit` in the indicated hand, from highest rank to
+/// lowest.
+pub fn cards_of_suit_desc(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> Vec<Card> {
+    data.hand(hand).filter(|card| card.suit == suit).rev().collect()
+}
+
+/// Returns the player whose partnership is currently winning the current
+/// trick, or None if no card has been played to it.
+pub fn side_winning_trick(data: &PlayPhaseData) -> Option<PlayerName> {
+    if data.current_trick.cards.is_empty() {
+        return None;
+    }
+    Some(trick_winner(&data.current_trick, data.trump()).player_name())
+}
+
+/// Returns the number of trumps the defenders may still hold, from the
+/// declarer's point of view: all trumps, less those in the declaring side's
+/// hands and those already played.
+///
+/// Returns 0 in a no trump contract.
+pub fn outstanding_trumps(data: &PlayPhaseData) -> usize {
+    let Some(trump) = data.trump() else {
+        return 0;
+    };
+    unseen_suit_counts(data)[&trump]
+}
+
+/// Returns the number of cards of each suit the declarer cannot see, i.e. all
+/// cards of the suit less those in the declaring side's hands and those
+/// already played. These are the cards the defenders may still hold.
+pub fn unseen_suit_counts(data: &PlayPhaseData) -> HashMap<Suit, usize> {
+    let mut result = enum_iterator::all::<Suit>()
+        .map(|suit| (suit, enum_iterator::cardinality::<Rank>()))
+        .collect::<HashMap<_, _>>();
+    let played = data.played_cards().map(|played| played.card);
+    for card in data.combined_hand(data.declarer()).into_iter().chain(played) {
+        *result.get_mut(&card.suit).unwrap() -= 1;
+    }
+    result
+}
+
+/// Returns the suits in which every card the indicated hand holds is a winner,
+/// i.e. neither opponent holds a card of the suit ranked above the hand's
+/// lowest card in it, in sorted order.
+pub fn established_suits(data: &PlayPhaseData, hand: HandIdentifier) -> Vec<Suit> {
+    let opponents = hand.next();
+    enum_iterator::all::<Suit>()
+        .filter(|&suit| {
+            let Some(lowest) = lowest_of_suit(data, hand, suit) else {
+                return false;
+            };
+            data.hand(opponents)
+                .chain(data.hand(opponents.partner()))
+                .all(|card| card.suit != suit || card.rank < lowest.rank)
+        })
+        .collect()
+}
+
+/// Returns the suit the partner of the indicated hand has asked for via a
+/// suit preference discard, if any.
+///
+/// A discard is a card which neither follows the suit led nor is a trump. The
+/// most recent discard by the partner ranked Seven or higher is read as
+/// showing interest in that suit; lower discards carry no preference.
+pub fn read_signal(data: &PlayPhaseData, hand: HandIdentifier) -> Option<Suit> {
+    let partner = hand.partner();
+    data.completed_tricks
+        .iter()
+        .map(|completed| &completed.trick)
+        .chain(Some(&data.current_trick))
+        .flat_map(|trick| {
+            let lead = trick.lead_card().map(|card| card.suit);
+            trick.cards.iter().filter(move |played| {
+                played.played_by == partner
+                    && Some(played.card.suit) != lead
+                    && Some(played.card.suit) != data.trump()
+            })
+        })
+        .rev()
+        .find(|played| played.card.rank >= Rank::Seven)
+        .map(|played| played.card.suit)
+}
+
+/// Returns true if the indicated hand can stop the opponents from running
+/// `suit`.
+///
+/// A hand stops a suit if it holds the highest card of the suit not held by
+/// either opponent, or if it holds an honor (Jack or higher) guarded by at
+/// least as many other cards of the suit as the opponents hold cards ranked
+/// above it, e.g. K-x against the Ace or Q-x-x against the Ace and King.
+pub fn has_stopper(data: &PlayPhaseData, hand: HandIdentifier, suit: Suit) -> bool {
+    let opponents = hand.next();
+    let length = data.hand(hand).filter(|card| card.suit == suit).count();
+    data.hand(hand).filter(|card| card.suit == suit).any(|card| {
+        let higher = data
+            .hand(opponents)
+            .chain(data.hand(opponents.partner()))
+            .filter(|c| c.suit == suit && c.rank > card.rank)
+            .count();
+        higher < length && (higher == 0 || card.rank >= Rank::Jack)
+    })
+}
+
+/// Estimates the number of times the lead can be moved from the hand `from` to
+/// its partner `to`.
+///
+/// Each card of a suit held by `to` which outranks every card of that suit
+/// held by the opponents is an entry, as long as `from` holds a card of the
+/// suit to lead towards it. Ruffs by the opponents are not considered.
+pub fn entries(data: &PlayPhaseData, from: HandIdentifier, to: HandIdentifier) -> usize {
+    let opponents = from.next();
+    enum_iterator::all::<Suit>()
+        .map(|suit| {
+            let best_opponent = data
+                .hand(opponents)
+                .chain(data.hand(opponents.partner()))
+                .filter(|card| card.suit == suit)
+                .max();
+            let winners = data
+                .hand(to)
+                .filter(|&card| card.suit == suit && Some(card) > best_opponent)
+                .count();
+            let leads = data.hand(from).filter(|card| card.suit == suit).count();
+            winners.min(leads)
+        })
+        .sum()
+}
+
+/// Returns the cards the indicated hand could discard to the current trick,
+/// ordered from safest to riskiest.
+///
+/// A discard is a legal play which neither follows the suit led nor is a
+/// trump. Cards from suits without an honor (Jack or higher) are safest,
+/// longest suit first, then low cards from suits which contain an honor, and
+/// finally the honors themselves. Within each group lower cards come first.
+///
+/// Returns an empty list if the hand is on lead or can follow suit.
+pub fn ranked_discards(data: &PlayPhaseData, hand: HandIdentifier) -> Vec<Card> {
+    let Some(lead) = lead_suit(data) else {
+        return vec![];
+    };
+    let length = |suit: Suit| data.hand(hand).filter(|card| card.suit == suit).count();
+    let has_honor =
+        |suit: Suit| data.hand(hand).any(|card| card.suit == suit && card.rank >= Rank::Jack);
+    let mut result = legal_plays(data, hand)
+        .into_iter()
+        .filter(|card| card.suit != lead && Some(card.suit) != data.trump())
+        .collect::<Vec<_>>();
+    result.sort_by_key(|card| {
+        let risk = if card.rank >= Rank::Jack {
+            2
+        } else if has_honor(card.suit) {
+            1
+        } else {
+            0
+        };
+        (risk, Reverse(length(card.suit)), card.rank)
+    });
+    result
+}
+
+/// Returns true if a hand which has not yet played to the current trick holds
+/// a card it could legally play which would beat the card currently winning
+/// the trick.
+///
+/// Returns false if no card has been led to the current trick or if it is
+/// already complete.
+pub fn trick_still_contestable(data: &PlayPhaseData) -> bool {
+    let Some(lead) = lead_suit(data) else {
+        return false;
+    };
+    let trump = data.trump();
+    let winning = data
+        .current_trick
+        .cards
+        .iter()
+        .map(|played| played.card)
+        .max_by(|&a, &b| compare_card_power(trump, lead, a, b))
+        .unwrap();
+    upcoming_turn_order(data).into_iter().any(|hand| {
+        let follows = data.hand(hand).any(|card| card.suit == lead);
+        data.hand(hand).any(|card| {
+            (!follows || card.suit == lead)
+                && compare_card_power(trump, lead, card, winning) == Ordering::Greater
+        })
+    })
+}
+
+/// Describes the cards of `suit` held between both hands of the partnership
+/// controlled by `side`, highest first, e.g. "AKx". Honors (Jack or higher)
+/// are written out and every other card is shown as 'x'.
+pub fn honor_holding(data: &PlayPhaseData, side: PlayerName, suit: Suit) -> String {
+    data.combined_hand(side)
+        .into_iter()
+        .filter(|card| card.suit == suit)
+        .rev()
+        .map(|card| if card.rank >= Rank::Jack { card.rank.to_string() } else { "x".to_string() })
+        .collect()
+}
+
+/// Returns every card of `suit` the user can see, paired with the hand which
+/// holds or played it: the cards still held by the user's hand and by the
+/// dummy, in that order, followed by the cards of the suit already played, in
+/// play order.
+///
+/// The dummy's cards are only included once the opening lead has been made.
+pub fn visible_suit_cards(data: &PlayPhaseData, suit: Suit) -> Vec<(HandIdentifier, Card)> {
+    let mut visible = vec![PlayerName::User.primary_hand()];
+    if !data.is_opening_lead() {
+        visible.push(data.dummy());
+    }
+    visible
+        .into_iter()
+        .flat_map(|hand| {
+            data.cards_by_suit(hand)
+                .remove(&suit)
+                .unwrap()
+                .into_iter()
+                .map(move |card| (hand, card))
+        })
+        .chain(
+            data.played_cards()
+                .filter(|played| played.card.suit == suit)
+                .map(|played| (played.played_by, played.card)),
+        )
+        .collect()
 }
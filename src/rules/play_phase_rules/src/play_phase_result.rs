@@ -0,0 +1,94 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::Contract;
+use play_phase_data::{PlayPhaseData, ScoreBreakdown};
+
+use crate::play_phase_analysis;
+
+/// Content of the overlay shown when a game ends, independent of how a
+/// frontend chooses to render it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultScreen {
+    /// Headline for the screen, e.g. "Contract Made".
+    pub title: String,
+    /// Contract and trick count, e.g. "8♠ by You: 9 tricks".
+    pub summary: String,
+    /// True if the declarer made the contract.
+    pub made: bool,
+    /// Tricks won by the declarer relative to the contract.
+    pub breakdown: ScoreBreakdown,
+    /// Actions offered to the user, in display order.
+    pub options: Vec<ResultOption>,
+}
+
+/// An action the user can take from the [ResultScreen].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultOption {
+    /// Step back through the cards played in the finished game.
+    Replay,
+    /// Deal a new game.
+    Restart,
+}
+
+impl ResultOption {
+    /// Label to show for this option.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Replay => "Replay",
+            Self::Restart => "New Game",
+        }
+    }
+}
+
+/// Builds the result screen for `contract` when the declarer won
+/// `tricks_won` tricks.
+pub fn build(contract: &Contract, tricks_won: usize) -> ResultScreen {
+    let breakdown = ScoreBreakdown::new(contract, tricks_won);
+    let made = breakdown.is_made();
+    let outcome = match breakdown.net() {
+        0 if made => "made exactly".to_string(),
+        net if made => format!("made with {net} overtrick{}", plural(net)),
+        net => format!("down {}", -net),
+    };
+    ResultScreen {
+        title: if made { "Contract Made" } else { "Contract Defeated" }.to_string(),
+        summary: format!(
+            "{}: {tricks_won} trick{}, {outcome}",
+            contract.describe(),
+            plural(tricks_won as i32)
+        ),
+        made,
+        breakdown,
+        options: vec![ResultOption::Replay, ResultOption::Restart],
+    }
+}
+
+/// Builds the result screen for `data`, or returns None if the game is not
+/// over yet.
+pub fn for_game(data: &PlayPhaseData) -> Option<ResultScreen> {
+    if !data.is_game_over() {
+        return None;
+    }
+    let tricks_won = play_phase_analysis::declarer_tricks(data);
+    Some(build(&data.contract, tricks_won))
+}
+
+fn plural(count: i32) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
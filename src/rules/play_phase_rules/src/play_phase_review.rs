@@ -0,0 +1,116 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::{PlayPhaseAction, PlayPhaseData, PlayedCard, Trick};
+
+use crate::play_phase_actions;
+
+/// Steps through the cards played in a game, reconstructing the state of the
+/// game before and after each play.
+///
+/// Step 0 is the game as dealt, and step `n` is the game after the first `n`
+/// cards have been played.
+#[derive(Debug, Clone)]
+pub struct PlayPhaseReview {
+    initial: PlayPhaseData,
+    plays: Vec<PlayedCard>,
+    step: usize,
+}
+
+impl PlayPhaseReview {
+    /// Creates a review of the cards played so far in `data`, starting at
+    /// step 0.
+    pub fn new(data: &PlayPhaseData) -> Self {
+        let initial = PlayPhaseData {
+            hands: data.initial_hands(),
+            current_trick: Trick::default(),
+            completed_tricks: vec![],
+            prior_tricks: data.prior_tricks,
+            contract: data.contract.clone(),
+            rules: data.rules,
+            events: vec![],
+        };
+        Self { initial, plays: data.played_cards().cloned().collect(), step: 0 }
+    }
+
+    /// Current position of this review
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Number of the final step of this review, i.e. the number of cards which
+    /// were played.
+    pub fn last_step(&self) -> usize {
+        self.plays.len()
+    }
+
+    /// Returns the state of the game at the current step.
+    pub fn current(&self) -> PlayPhaseData {
+        self.state_at_step(self.step)
+    }
+
+    /// Returns the state of the game just before the card at index
+    /// `card_in_trick` (starting from 0) was played to trick number `trick`,
+    /// without changing the current step.
+    ///
+    /// Trick numbers match [PlayPhaseData::current_trick_number], so they start
+    /// from 1 and count any prior tricks.
+    ///
+    /// Panics if that card was never played.
+    pub fn state_at_trick(&self, trick: usize, card_in_trick: usize) -> PlayPhaseData {
+        let first = self.initial.prior_tricks.total() + 1;
+        assert!(
+            trick >= first && card_in_trick < 4,
+            "Invalid play {card_in_trick} in trick {trick}"
+        );
+        let step = (trick - first) * 4 + card_in_trick;
+        assert!(step < self.last_step(), "Card {card_in_trick} of trick {trick} was not played");
+        self.state_at_step(step)
+    }
+
+    fn state_at_step(&self, step: usize) -> PlayPhaseData {
+        let mut result = self.initial.clone();
+        for played in &self.plays[..step] {
+            play_phase_actions::handle_action(
+                &mut result,
+                PlayPhaseAction::PlayCard(
+                    played.played_by.player_name(),
+                    played.played_by,
+                    played.card,
+                ),
+            );
+        }
+        result
+    }
+
+    /// Advances to the next step, returning the state of the game at that
+    /// step, or None if this review is already at the last step.
+    pub fn next_step(&mut self) -> Option<PlayPhaseData> {
+        if self.step == self.last_step() {
+            return None;
+        }
+        self.step += 1;
+        Some(self.current())
+    }
+
+    /// Moves back to the previous step, returning the state of the game at
+    /// that step, or None if this review is already at step 0.
+    pub fn previous_step(&mut self) -> Option<PlayPhaseData> {
+        if self.step == 0 {
+            return None;
+        }
+        self.step -= 1;
+        Some(self.current())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::Contract;
+use primitives::{PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn describe_trump_contract() {
+    let contract = Contract { declarer: PlayerName::User, trump: Some(Suit::Spades), bid: 7 };
+    assert_eq!(contract.describe(), "7♠ by You");
+}
+
+#[test]
+fn describe_no_trump_contract() {
+    let contract = Contract { declarer: PlayerName::Opponent, trump: None, bid: 7 };
+    assert_eq!(contract.describe(), "7 No Trump by Opponents");
+}
+
+#[test]
+fn slam_contracts() {
+    let contract = |bid| Contract { declarer: PlayerName::User, trump: Some(Suit::Hearts), bid };
+    assert!(!contract(11).is_slam());
+    assert!(contract(12).is_slam());
+    assert!(contract(13).is_slam());
+}
+
+#[test]
+fn new_contract_in_range() {
+    for bid in [7, 8, 13] {
+        let contract = Contract::new(PlayerName::User, None, bid).unwrap();
+        assert!(contract.is_valid());
+        assert_eq!(contract.bid, bid);
+    }
+}
+
+#[test]
+fn new_contract_out_of_range() {
+    assert!(Contract::new(PlayerName::User, None, 6).is_err());
+    assert!(Contract::new(PlayerName::User, Some(Suit::Clubs), 14).is_err());
+    assert!(!Contract { declarer: PlayerName::User, trump: None, bid: 0 }.is_valid());
+}
+
+#[test]
+fn trump_contract_without_trumps_is_not_playable() {
+    let game = test_games::game("2C 3H", "4S 5S", "4C 5H", "6S 7S");
+    assert!(!game.contract.is_playable(&game.hands));
+}
+
+#[test]
+fn trump_contract_with_trumps_is_playable() {
+    let game = test_games::game("2C 3H", "4S 5S", "4C 2S", "6S 7S");
+    assert!(game.contract.is_playable(&game.hands));
+}
+
+#[test]
+fn no_trump_contract_is_playable() {
+    let mut game = test_games::game("2C 3H", "4S 5S", "4C 5H", "6S 7S");
+    game.contract.trump = None;
+    assert!(game.contract.is_playable(&game.hands));
+}
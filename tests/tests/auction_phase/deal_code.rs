@@ -0,0 +1,58 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::{Contract, GameConfig};
+use primitives::{PlayerName, Suit};
+
+#[test]
+fn same_code_yields_identical_hands() {
+    let first = auction_phase_mutations::new_game_from_code("2f1c").unwrap();
+    let second = auction_phase_mutations::new_game_from_code("2f1c").unwrap();
+    assert_eq!(first.hands, second.hands);
+    let other = auction_phase_mutations::new_game_from_code("2f1d").unwrap();
+    assert_ne!(first.hands, other.hands);
+}
+
+#[test]
+fn code_round_trips_config() {
+    let config = GameConfig {
+        seed: Some(0xbeef),
+        contract: Contract { declarer: PlayerName::Opponent, trump: None, bid: 7 },
+        ..GameConfig::default()
+    };
+    let code = auction_phase_mutations::game_code(&config).unwrap();
+    assert_eq!(code, "beef-7NO");
+    let parsed = auction_phase_mutations::config_from_code(&code).unwrap();
+    assert_eq!(parsed.seed, config.seed);
+    assert_eq!(parsed.contract, config.contract);
+}
+
+#[test]
+fn code_without_contract_uses_default() {
+    let config = auction_phase_mutations::config_from_code("10").unwrap();
+    assert_eq!(config.seed, Some(16));
+    assert_eq!(config.contract.trump, Some(Suit::Spades));
+}
+
+#[test]
+fn invalid_codes_are_rejected() {
+    assert!(auction_phase_mutations::config_from_code("xyz").is_err());
+    assert!(auction_phase_mutations::config_from_code("10-8X").is_err());
+    assert!(auction_phase_mutations::config_from_code("10-20SU").is_err());
+}
+
+#[test]
+fn unseeded_config_has_no_code() {
+    assert_eq!(auction_phase_mutations::game_code(&GameConfig::default()), None);
+}
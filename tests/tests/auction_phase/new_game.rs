@@ -0,0 +1,82 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::{Contract, GameConfig};
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+#[test]
+fn new_game_deals_complete_deck() {
+    let game = auction_phase_mutations::new_game(&seeded(1));
+    assert!(auction_phase_mutations::is_complete_deal(&game.hands));
+}
+
+#[test]
+fn duplicate_card_is_not_complete_deal() {
+    let mut game = auction_phase_mutations::new_game(&seeded(1));
+    let card = game.hand(HandIdentifier::North).next().unwrap();
+    let removed = game.hand(HandIdentifier::South).next().unwrap();
+    let south = game.hands.get_mut(&HandIdentifier::South).unwrap();
+    south.remove(&removed);
+    south.insert(card);
+    assert!(!auction_phase_mutations::is_complete_deal(&game.hands));
+}
+
+#[test]
+fn short_hand_is_not_complete_deal() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert!(!auction_phase_mutations::is_complete_deal(&game.hands));
+}
+
+#[test]
+fn new_game_from_config() {
+    let config = GameConfig {
+        seed: Some(7),
+        contract: Contract { declarer: PlayerName::Opponent, trump: None, bid: 9 },
+        hand_size: 5,
+        ..GameConfig::default()
+    };
+    let game = auction_phase_mutations::new_game(&config);
+    assert_eq!(game.declarer(), PlayerName::Opponent);
+    assert_eq!(game.trump(), None);
+    assert_eq!(game.contract.bid, 9);
+    assert!(game.hands.values().all(|hand| hand.len() == 5));
+    assert_eq!(auction_phase_mutations::new_game(&config), game);
+}
+
+#[test]
+fn hand_size_bounds() {
+    let game = |hand_size| GameConfig { hand_size, ..seeded(1) };
+    let smallest = auction_phase_mutations::new_game(&game(1));
+    assert!(smallest.hands.values().all(|hand| hand.len() == 1));
+    let largest = auction_phase_mutations::new_game(&game(13));
+    assert!(auction_phase_mutations::is_complete_deal(&largest.hands));
+}
+
+#[test]
+#[should_panic(expected = "Invalid hand size 0")]
+fn empty_hands_rejected() {
+    auction_phase_mutations::new_game(&GameConfig { hand_size: 0, ..seeded(1) });
+}
+
+#[test]
+#[should_panic(expected = "Invalid hand size 14")]
+fn oversized_hands_rejected() {
+    auction_phase_mutations::new_game(&GameConfig { hand_size: 14, ..seeded(1) });
+}
+
+fn seeded(seed: u64) -> GameConfig {
+    GameConfig { seed: Some(seed), ..GameConfig::default() }
+}
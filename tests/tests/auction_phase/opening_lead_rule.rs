@@ -0,0 +1,48 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::{Contract, GameConfig, OpeningLeadRule, Rules};
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, PlayerName};
+
+#[test]
+fn declarer_leads() {
+    let game = new_game(PlayerName::User, OpeningLeadRule::Declarer);
+    assert_eq!(play_phase_queries::next_to_play(&game), HandIdentifier::South);
+    let game = new_game(PlayerName::Opponent, OpeningLeadRule::Declarer);
+    assert_eq!(play_phase_queries::next_to_play(&game), HandIdentifier::West);
+}
+
+#[test]
+fn left_of_declarer_leads() {
+    let game = new_game(PlayerName::User, OpeningLeadRule::LeftOfDeclarer);
+    assert_eq!(play_phase_queries::next_to_play(&game), HandIdentifier::West);
+    let game = new_game(PlayerName::Opponent, OpeningLeadRule::LeftOfDeclarer);
+    assert_eq!(play_phase_queries::next_to_play(&game), HandIdentifier::North);
+}
+
+#[test]
+fn default_rule_is_declarer() {
+    assert_eq!(GameConfig::default().rules.opening_lead, OpeningLeadRule::Declarer);
+}
+
+fn new_game(declarer: PlayerName, opening_lead: OpeningLeadRule) -> PlayPhaseData {
+    auction_phase_mutations::new_game(&GameConfig {
+        seed: Some(2),
+        contract: Contract { declarer, trump: None, bid: 7 },
+        rules: Rules { opening_lead },
+        ..GameConfig::default()
+    })
+}
@@ -0,0 +1,43 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+use primitives::HandIdentifier;
+
+fn deal() -> play_phase_data::PlayPhaseData {
+    auction_phase_mutations::new_game(&GameConfig { seed: Some(4), ..GameConfig::default() })
+}
+
+#[test]
+fn valid_deal() {
+    assert!(auction_phase_mutations::validate_deal(&deal().hands).is_ok());
+}
+
+#[test]
+fn duplicate_card_across_hands() {
+    let mut game = deal();
+    let card = game.hand(HandIdentifier::North).next().unwrap();
+    game.hands.get_mut(&HandIdentifier::East).unwrap().insert(card);
+    let error = auction_phase_mutations::validate_deal(&game.hands).unwrap_err();
+    assert!(error.to_string().contains("held by both North and East"), "{error}");
+}
+
+#[test]
+fn missing_card() {
+    let mut game = deal();
+    let card = game.hand(HandIdentifier::West).next().unwrap();
+    game.hands.get_mut(&HandIdentifier::West).unwrap().remove(&card);
+    let error = auction_phase_mutations::validate_deal(&game.hands).unwrap_err();
+    assert_eq!(error.to_string(), format!("{card} is not held by any hand"));
+}
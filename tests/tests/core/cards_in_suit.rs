@@ -0,0 +1,34 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{Card, Rank, Suit};
+
+#[test]
+fn higher_in_suit() {
+    let queen = Card::new(Suit::Clubs, Rank::Queen);
+    assert_eq!(
+        queen.higher_in_suit().collect::<Vec<_>>(),
+        vec![Card::new(Suit::Clubs, Rank::King), Card::new(Suit::Clubs, Rank::Ace)]
+    );
+}
+
+#[test]
+fn lower_in_suit() {
+    let four = Card::new(Suit::Hearts, Rank::Four);
+    assert_eq!(
+        four.lower_in_suit().collect::<Vec<_>>(),
+        vec![Card::new(Suit::Hearts, Rank::Two), Card::new(Suit::Hearts, Rank::Three)]
+    );
+    assert_eq!(Card::new(Suit::Hearts, Rank::Two).lower_in_suit().count(), 0);
+}
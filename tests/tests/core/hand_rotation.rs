@@ -0,0 +1,65 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+#[test]
+fn rotate_by_seats() {
+    let north = HandIdentifier::North;
+    assert_eq!(north.rotate(0), HandIdentifier::North);
+    assert_eq!(north.rotate(1), HandIdentifier::East);
+    assert_eq!(north.rotate(2), HandIdentifier::South);
+    assert_eq!(north.rotate(3), HandIdentifier::West);
+    assert_eq!(north.rotate(4), HandIdentifier::North);
+}
+
+#[test]
+fn seats_until() {
+    let west = HandIdentifier::West;
+    assert_eq!(west.seats_until(HandIdentifier::West), 0);
+    assert_eq!(west.seats_until(HandIdentifier::North), 1);
+    assert_eq!(west.seats_until(HandIdentifier::South), 3);
+    for n in 0..4 {
+        assert_eq!(west.seats_until(west.rotate(n)), n);
+    }
+}
+
+#[test]
+fn seat_index_round_trip() {
+    for (index, hand) in enum_iterator::all::<HandIdentifier>().enumerate() {
+        assert_eq!(hand.seat_index(), index);
+        assert_eq!(HandIdentifier::from_seat_index(index), hand);
+    }
+    assert_eq!(HandIdentifier::from_seat_index(5), HandIdentifier::East);
+}
+
+#[test]
+fn seat_index_orders_all_hands() {
+    let mut hands = vec![
+        HandIdentifier::West,
+        HandIdentifier::South,
+        HandIdentifier::North,
+        HandIdentifier::East,
+    ];
+    hands.sort_by_key(HandIdentifier::seat_index);
+    assert_eq!(
+        hands,
+        vec![
+            HandIdentifier::North,
+            HandIdentifier::East,
+            HandIdentifier::South,
+            HandIdentifier::West
+        ]
+    );
+}
@@ -12,4 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cards_in_suit;
+pub mod hand_rotation;
 pub mod hello;
+pub mod suit_color;
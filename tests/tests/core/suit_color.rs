@@ -0,0 +1,40 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::Suit;
+
+#[test]
+fn red_suits() {
+    assert!(Suit::Hearts.is_red());
+    assert!(Suit::Diamonds.is_red());
+    assert!(!Suit::Spades.is_red());
+    assert!(!Suit::Clubs.is_red());
+}
+
+#[test]
+fn same_color_partners() {
+    assert_eq!(Suit::Clubs.same_color(), Suit::Spades);
+    assert_eq!(Suit::Spades.same_color(), Suit::Clubs);
+    assert_eq!(Suit::Diamonds.same_color(), Suit::Hearts);
+    assert_eq!(Suit::Hearts.same_color(), Suit::Diamonds);
+}
+
+#[test]
+fn other_colors() {
+    for suit in enum_iterator::all::<Suit>() {
+        let others = suit.other_colors();
+        assert!(others.iter().all(|other| other.is_red() != suit.is_red()));
+        assert_ne!(others[0], others[1]);
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use play_phase_display::animation_queue::{AnimationQueue, ANIMATION_DELAY};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn plays_are_shown_in_order() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    let mut queue = AnimationQueue::default();
+    queue.push_updates(&game);
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    queue.push_updates(&game);
+
+    let first = queue.tick(Duration::ZERO).unwrap();
+    assert!(first.is_opening_lead());
+    assert!(queue.tick(Duration::ZERO).is_none());
+
+    let second = queue.tick(ANIMATION_DELAY).unwrap();
+    assert_eq!(second.current_trick.cards.len(), 1);
+    assert_eq!(second.current_trick.lead_card(), Some(test_games::card("4C")));
+
+    assert!(queue.tick(ANIMATION_DELAY / 2).is_none());
+    let third = queue.tick(ANIMATION_DELAY / 2).unwrap();
+    assert_eq!(third.current_trick.cards.len(), 2);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn update_without_plays_queues_current_state() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let mut queue = AnimationQueue::default();
+    queue.push_updates(&game);
+    queue.push_updates(&game);
+    assert!(queue.tick(Duration::ZERO).is_some());
+    assert!(queue.tick(ANIMATION_DELAY).is_some());
+    assert!(queue.is_empty());
+}
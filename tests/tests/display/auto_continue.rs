@@ -0,0 +1,76 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::auto_continue;
+use play_phase_display::game_settings::GameSettings;
+use play_phase_display::play_phase_events::PlayPhaseUpdateEvent;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn clears_completed_trick_after_delay() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default())
+        .insert_resource(GameSettings {
+            auto_continue: true,
+            auto_continue_delay: Duration::from_millis(1000),
+            ..default()
+        })
+        .insert_resource(game)
+        .add_event::<PlayPhaseUpdateEvent>()
+        .add_systems(Update, auto_continue::update);
+
+    advance(&mut app, 600);
+    assert_eq!(app.world.resource::<PlayPhaseData>().current_trick.cards.len(), 4);
+    advance(&mut app, 600);
+    let data = app.world.resource::<PlayPhaseData>();
+    assert!(data.current_trick.cards.is_empty());
+    assert_eq!(data.completed_tricks.len(), 1);
+    assert_eq!(data.completed_tricks[0].winner, HandIdentifier::West);
+}
+
+#[test]
+fn does_nothing_when_disabled() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default())
+        .init_resource::<GameSettings>()
+        .insert_resource(game)
+        .add_event::<PlayPhaseUpdateEvent>()
+        .add_systems(Update, auto_continue::update);
+
+    advance(&mut app, 5000);
+    assert_eq!(app.world.resource::<PlayPhaseData>().current_trick.cards.len(), 4);
+}
+
+fn advance(app: &mut App, millis: u64) {
+    app.world.resource_mut::<Time>().advance_by(Duration::from_millis(millis));
+    app.update();
+}
@@ -0,0 +1,56 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::auto_play;
+use play_phase_display::game_settings::GameSettings;
+use play_phase_display::play_phase_events::PlayPhaseUpdateEvent;
+use primitives::HandIdentifier;
+
+use crate::test_games::{self, card};
+
+#[test]
+fn plays_forced_card_then_waits_for_choice() {
+    let mut app = app(true);
+    app.update();
+    let data = app.world.resource::<PlayPhaseData>();
+    assert_eq!(data.current_trick.cards.len(), 2);
+    assert_eq!(data.current_trick.cards[1].played_by, HandIdentifier::West);
+    assert_eq!(data.current_trick.cards[1].card, card("5C"));
+
+    app.update();
+    assert_eq!(app.world.resource::<PlayPhaseData>().current_trick.cards.len(), 2);
+}
+
+#[test]
+fn does_nothing_when_disabled() {
+    let mut app = app(false);
+    app.update();
+    assert_eq!(app.world.resource::<PlayPhaseData>().current_trick.cards.len(), 1);
+}
+
+/// South has led a club, so West must follow with its only club and North
+/// then chooses between two.
+fn app(auto_play_forced: bool) -> App {
+    let mut game = test_games::game("2C 7C", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+
+    let mut app = App::new();
+    app.insert_resource(GameSettings { auto_play_forced, ..default() })
+        .insert_resource(game)
+        .add_event::<PlayPhaseUpdateEvent>()
+        .add_systems(Update, auto_play::auto_play_forced);
+    app
+}
@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_display::camera_focus::{self, FOCUS_SCALE};
+use primitives::HandIdentifier;
+
+#[test]
+fn whole_table_when_unfocused() {
+    assert_eq!(camera_focus::camera_target(None, Vec2::new(1000.0, 800.0)), (Vec2::ZERO, 1.0));
+}
+
+#[test]
+fn target_for_each_hand() {
+    let size = Vec2::new(1000.0, 800.0);
+    let offset = 1.0 - FOCUS_SCALE;
+    let expected = [
+        (HandIdentifier::North, Vec2::new(0.0, 400.0 * offset)),
+        (HandIdentifier::East, Vec2::new(500.0 * offset, 0.0)),
+        (HandIdentifier::South, Vec2::new(0.0, -400.0 * offset)),
+        (HandIdentifier::West, Vec2::new(-500.0 * offset, 0.0)),
+    ];
+    for (hand, position) in expected {
+        let (target, scale) = camera_focus::camera_target(Some(hand), size);
+        assert!(target.abs_diff_eq(position, 1e-3), "{hand:?}: {target}");
+        assert_eq!(scale, FOCUS_SCALE);
+    }
+}
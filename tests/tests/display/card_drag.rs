@@ -0,0 +1,87 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::math::Vec2;
+use play_phase_display::card_drag;
+use play_phase_display::confirm_play::PendingPlay;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn drop_on_trick_plays_legal_card() {
+    let game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let card = test_games::card("4C");
+    assert!(card_drag::drop_plays_card(&game, HandIdentifier::South, card, Vec2::new(10.0, 20.0)));
+}
+
+#[test]
+fn drop_outside_trick_does_not_play() {
+    let game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let card = test_games::card("4C");
+    assert!(!card_drag::drop_plays_card(
+        &game,
+        HandIdentifier::South,
+        card,
+        Vec2::new(0.0, -300.0)
+    ));
+}
+
+#[test]
+fn drop_of_illegal_card_does_not_play() {
+    let mut game = test_games::game("2C", "3C", "4C 5H", "5C 6D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    let card = test_games::card("6D");
+    assert!(!card_drag::drop_plays_card(&game, HandIdentifier::West, card, Vec2::ZERO));
+}
+
+#[test]
+fn drop_with_confirmation_only_selects() {
+    let mut game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let mut pending = PendingPlay::default();
+    let card = test_games::card("4C");
+    let south = HandIdentifier::South;
+    assert!(!card_drag::handle_drop(&mut game, &mut pending, true, south, card, Vec2::ZERO));
+    assert_eq!(pending.selected, Some((south, card)));
+    assert!(game.current_trick.cards.is_empty());
+
+    assert!(card_drag::handle_drop(&mut game, &mut pending, true, south, card, Vec2::ZERO));
+    assert_eq!(pending.selected, None);
+    assert_eq!(game.current_trick.lead_card(), Some(card));
+}
+
+#[test]
+fn drop_without_confirmation_plays_and_clears_selection() {
+    let mut game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let mut pending = PendingPlay::default();
+    let south = HandIdentifier::South;
+    pending.select(&mut game, true, south, test_games::card("5H"));
+    let card = test_games::card("4C");
+    assert!(card_drag::handle_drop(&mut game, &mut pending, false, south, card, Vec2::ZERO));
+    assert_eq!(pending.selected, None);
+    assert_eq!(game.current_trick.lead_card(), Some(card));
+}
+
+#[test]
+fn drop_outside_trick_clears_selection() {
+    let mut game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let mut pending = PendingPlay::default();
+    let south = HandIdentifier::South;
+    let card = test_games::card("4C");
+    pending.select(&mut game, true, south, card);
+    let away = Vec2::new(0.0, -300.0);
+    assert!(!card_drag::handle_drop(&mut game, &mut pending, true, south, card, away));
+    assert_eq!(pending.selected, None);
+    assert!(game.current_trick.cards.is_empty());
+}
@@ -0,0 +1,62 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use display_utils::object_display::{Displayable, ObjectDisplayPosition};
+use play_phase_display::card_hover::{self, Hovered};
+use primitives::HandIdentifier;
+
+#[test]
+fn hovering_raises_and_restores_card() {
+    let mut app = App::new();
+    app.add_systems(Update, card_hover::update);
+    let card = app
+        .world
+        .spawn((
+            Transform::from_xyz(10.0, 0.0, 3.0),
+            Displayable {
+                position: ObjectDisplayPosition::InHand(HandIdentifier::South),
+                sorting_key: 0,
+            },
+        ))
+        .id();
+
+    app.world.entity_mut(card).insert(Hovered);
+    app.update();
+    let raised = app.world.get::<Transform>(card).unwrap().translation;
+    assert_eq!(raised, Vec3::new(10.0, card_hover::HOVER_RAISE, 3.0 + card_hover::HOVER_Z));
+
+    app.world.entity_mut(card).remove::<Hovered>();
+    app.update();
+    assert_eq!(app.world.get::<Transform>(card).unwrap().translation, Vec3::new(10.0, 0.0, 3.0));
+}
+
+#[test]
+fn opponent_cards_do_not_respond_to_hover() {
+    let mut app = App::new();
+    app.add_systems(Update, card_hover::update);
+    let card = app
+        .world
+        .spawn((
+            Transform::from_xyz(0.0, 5.0, 1.0),
+            Displayable {
+                position: ObjectDisplayPosition::InHand(HandIdentifier::West),
+                sorting_key: 0,
+            },
+            Hovered,
+        ))
+        .id();
+    app.update();
+    assert_eq!(app.world.get::<Transform>(card).unwrap().translation, Vec3::new(0.0, 5.0, 1.0));
+}
@@ -0,0 +1,100 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use display_utils::object_display::ObjectDisplayPosition;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::play_phase_events::{self, TrickDisplayOrder};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn card_in_hand() {
+    let game = test_games::game("2C", "3C", "4C 5H", "5C");
+    let displayable = play_phase_events::card_position(
+        &game,
+        test_games::card("5H"),
+        TrickDisplayOrder::PlayOrder,
+    )
+    .unwrap();
+    assert_eq!(displayable.position, ObjectDisplayPosition::InHand(HandIdentifier::South));
+    assert_eq!(displayable.sorting_key, 1);
+}
+
+#[test]
+fn missing_card_returns_none() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert!(play_phase_events::card_position(
+        &game,
+        test_games::card("AS"),
+        TrickDisplayOrder::PlayOrder
+    )
+    .is_none());
+}
+
+#[test]
+fn trick_sorted_in_play_order() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    let key = |card| {
+        play_phase_events::card_position(
+            &game,
+            test_games::card(card),
+            TrickDisplayOrder::PlayOrder,
+        )
+        .unwrap()
+        .sorting_key
+    };
+    assert_eq!((key("4C"), key("5C"), key("2C")), (0, 1, 2));
+}
+
+#[test]
+fn trick_sorted_in_seat_order() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    let key = |card| {
+        play_phase_events::card_position(
+            &game,
+            test_games::card(card),
+            TrickDisplayOrder::SeatOrder,
+        )
+        .unwrap()
+        .sorting_key
+    };
+    assert_eq!((key("2C"), key("4C"), key("5C")), (0, 2, 3));
+}
+
+#[test]
+fn opponent_hand_count_decreases_after_play() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    let all_cards = test_games::cards("2C 2H 3C 3H 4C 4H 5C 5H");
+    let west_count = |game: &PlayPhaseData| {
+        all_cards
+            .iter()
+            .filter_map(|&card| {
+                play_phase_events::card_position(game, card, TrickDisplayOrder::PlayOrder)
+            })
+            .filter(|d| d.position == ObjectDisplayPosition::InHand(HandIdentifier::West))
+            .count()
+    };
+    assert_eq!(west_count(&game), 2);
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    assert_eq!(west_count(&game), game.hand(HandIdentifier::West).count());
+    assert_eq!(west_count(&game), 1);
+}
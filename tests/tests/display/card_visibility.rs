@@ -0,0 +1,84 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use play_phase_display::card_visibility::{self, DebugReveal, DummyReveal};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn dummy_hidden_before_opening_lead() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let mut reveal = DummyReveal::default();
+    assert!(!reveal.update(&game));
+    assert!(!card_visibility::is_hand_visible(
+        &game,
+        HandIdentifier::North,
+        &reveal,
+        &DebugReveal::default()
+    ));
+    assert!(card_visibility::is_hand_visible(
+        &game,
+        HandIdentifier::South,
+        &reveal,
+        &DebugReveal::default()
+    ));
+}
+
+#[test]
+fn dummy_revealed_once_after_opening_lead() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    let mut reveal = DummyReveal::default();
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(reveal.update(&game));
+    assert!(reveal.revealed);
+    assert!(card_visibility::is_hand_visible(
+        &game,
+        HandIdentifier::North,
+        &reveal,
+        &DebugReveal::default()
+    ));
+    assert!(!reveal.update(&game));
+}
+
+#[test]
+fn debug_reveal_shows_dummy() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let reveal = DummyReveal::default();
+    let debug = DebugReveal { enabled: true };
+    assert!(card_visibility::is_hand_visible(&game, HandIdentifier::North, &reveal, &debug));
+}
+
+#[test]
+fn d_key_toggles_debug_reveal() {
+    let mut app = App::new();
+    app.init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<DebugReveal>()
+        .add_systems(Update, card_visibility::toggle_debug_reveal);
+
+    app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyD);
+    app.update();
+    assert!(app.world.resource::<DebugReveal>().enabled);
+
+    app.world.resource_mut::<ButtonInput<KeyCode>>().clear();
+    app.update();
+    assert!(app.world.resource::<DebugReveal>().enabled);
+
+    app.world.resource_mut::<ButtonInput<KeyCode>>().release(KeyCode::KeyD);
+    app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyD);
+    app.update();
+    assert!(!app.world.resource::<DebugReveal>().enabled);
+}
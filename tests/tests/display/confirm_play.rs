@@ -0,0 +1,68 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_display::confirm_play::PendingPlay;
+use primitives::HandIdentifier;
+
+use crate::test_games::{self, card};
+
+#[test]
+fn select_confirm_commit() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    let mut pending = PendingPlay::default();
+    assert!(!pending.select(&mut game, true, HandIdentifier::South, card("4C")));
+    assert_eq!(pending.selected, Some((HandIdentifier::South, card("4C"))));
+    assert!(game.current_trick.cards.is_empty());
+
+    assert!(pending.select(&mut game, true, HandIdentifier::South, card("4C")));
+    assert_eq!(pending.selected, None);
+    assert_eq!(game.current_trick.lead_card(), Some(card("4C")));
+}
+
+#[test]
+fn confirm_plays_selected_card() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    let mut pending = PendingPlay::default();
+    pending.select(&mut game, true, HandIdentifier::South, card("4D"));
+    assert!(pending.confirm(&mut game));
+    assert_eq!(game.current_trick.lead_card(), Some(card("4D")));
+}
+
+#[test]
+fn select_cancel() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    let mut pending = PendingPlay::default();
+    pending.select(&mut game, true, HandIdentifier::South, card("4C"));
+    pending.cancel();
+    assert_eq!(pending.selected, None);
+    assert!(!pending.confirm(&mut game));
+    assert!(game.current_trick.cards.is_empty());
+}
+
+#[test]
+fn selecting_unplayable_card_cancels() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    let mut pending = PendingPlay::default();
+    pending.select(&mut game, true, HandIdentifier::South, card("4C"));
+    assert!(!pending.select(&mut game, true, HandIdentifier::North, card("2C")));
+    assert_eq!(pending.selected, None);
+}
+
+#[test]
+fn plays_immediately_without_confirmation() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    let mut pending = PendingPlay::default();
+    assert!(pending.select(&mut game, false, HandIdentifier::South, card("4C")));
+    assert_eq!(game.current_trick.lead_card(), Some(card("4C")));
+}
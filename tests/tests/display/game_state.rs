@@ -0,0 +1,59 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::auto_play;
+use play_phase_display::game_settings::GameSettings;
+use play_phase_display::game_state::{self, GameState};
+use play_phase_display::play_phase_events::PlayPhaseUpdateEvent;
+
+use crate::test_games;
+
+fn app(state: GameState) -> App {
+    let mut app = App::new();
+    app.insert_resource(GameSettings { auto_play_forced: true, ..default() })
+        .insert_resource(state)
+        .insert_resource(test_games::game("2C", "3C", "4C", "5C"))
+        .add_event::<PlayPhaseUpdateEvent>()
+        .add_systems(Update, auto_play::auto_play_forced.run_if(game_state::is_playing));
+    app
+}
+
+#[test]
+fn plays_are_ignored_while_paused() {
+    let mut app = app(GameState::Paused);
+    app.update();
+    assert!(app.world.resource::<PlayPhaseData>().current_trick.cards.is_empty());
+}
+
+#[test]
+fn plays_resume_when_unpaused() {
+    let mut app = app(GameState::Paused);
+    app.update();
+    *app.world.resource_mut::<GameState>() = GameState::Playing;
+    app.update();
+    assert_eq!(app.world.resource::<PlayPhaseData>().current_trick.cards.len(), 1);
+}
+
+#[test]
+fn pause_key_toggles_state() {
+    let mut app = App::new();
+    app.init_resource::<GameState>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_systems(Update, game_state::toggle_pause);
+    app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyP);
+    app.update();
+    assert_eq!(*app.world.resource::<GameState>(), GameState::Paused);
+}
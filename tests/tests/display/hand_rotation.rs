@@ -0,0 +1,45 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use play_phase_display::play_phase_spawn;
+use primitives::HandIdentifier;
+
+#[test]
+fn side_hands_are_rotated() {
+    assert_eq!(
+        play_phase_spawn::hand_rotation(HandIdentifier::East),
+        Quat::from_rotation_z(FRAC_PI_2)
+    );
+    assert_eq!(
+        play_phase_spawn::hand_rotation(HandIdentifier::West),
+        Quat::from_rotation_z(-FRAC_PI_2)
+    );
+}
+
+#[test]
+fn side_hands_extend_towards_center() {
+    let east = play_phase_spawn::hand_rotation(HandIdentifier::East) * Vec3::Y;
+    let west = play_phase_spawn::hand_rotation(HandIdentifier::West) * Vec3::Y;
+    assert!(east.x < -0.99);
+    assert!(west.x > 0.99);
+}
+
+#[test]
+fn north_and_south_are_upright() {
+    assert_eq!(play_phase_spawn::hand_rotation(HandIdentifier::North), Quat::IDENTITY);
+    assert_eq!(play_phase_spawn::hand_rotation(HandIdentifier::South), Quat::IDENTITY);
+}
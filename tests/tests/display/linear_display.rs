@@ -0,0 +1,76 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use display_utils::linear_display::{self, LinearDisplay, LinearDisplayDirection, ManualPosition};
+
+fn app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_systems(Update, linear_display::update);
+    let display = app
+        .world
+        .spawn(LinearDisplay { size: 100.0, direction: LinearDisplayDirection::Horizontal })
+        .id();
+    (app, display)
+}
+
+fn add_child(app: &mut App, display: Entity) -> Entity {
+    let child = app.world.spawn(Transform::default()).id();
+    app.world.entity_mut(display).add_child(child);
+    child
+}
+
+fn x(app: &App, entity: Entity) -> f32 {
+    app.world.get::<Transform>(entity).unwrap().translation.x
+}
+
+#[test]
+fn children_positioned_after_child_added() {
+    let (mut app, display) = app();
+    let first = add_child(&mut app, display);
+    let second = add_child(&mut app, display);
+    app.update();
+    assert_eq!((x(&app, first), x(&app, second)), (-50.0, 50.0));
+
+    let third = add_child(&mut app, display);
+    app.update();
+    assert_eq!((x(&app, first), x(&app, second), x(&app, third)), (-50.0, 0.0, 50.0));
+}
+
+#[test]
+fn unchanged_display_is_not_laid_out() {
+    let (mut app, display) = app();
+    let child = add_child(&mut app, display);
+    app.update();
+    app.world.get_mut::<Transform>(child).unwrap().translation.x = 20.0;
+    app.update();
+    assert_eq!(x(&app, child), 20.0);
+}
+
+#[test]
+fn removing_manual_position_lays_out_again() {
+    let (mut app, display) = app();
+    let first = add_child(&mut app, display);
+    let second = add_child(&mut app, display);
+    app.update();
+
+    app.world.entity_mut(first).insert(ManualPosition);
+    app.world.get_mut::<Transform>(first).unwrap().translation.x = 20.0;
+    app.update();
+    assert_eq!(x(&app, first), 20.0);
+
+    app.world.entity_mut(first).remove::<ManualPosition>();
+    app.update();
+    assert_eq!((x(&app, first), x(&app, second)), (-50.0, 50.0));
+}
@@ -0,0 +1,30 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod animation_queue;
+pub mod auto_continue;
+pub mod auto_play;
+pub mod camera_focus;
+pub mod card_drag;
+pub mod card_hover;
+pub mod card_position;
+pub mod card_visibility;
+pub mod confirm_play;
+pub mod game_state;
+pub mod hand_rotation;
+pub mod linear_display;
+pub mod trick_highlight;
+pub mod trick_sweep;
+pub mod trump_override;
+pub mod turn_timer;
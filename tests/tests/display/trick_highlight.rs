@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_display::trick_highlight;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn no_highlight_during_trick() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    assert_eq!(trick_highlight::highlighted_card(&game), None);
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    assert_eq!(trick_highlight::highlighted_card(&game), None);
+}
+
+#[test]
+fn winner_highlighted_until_next_trick() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(trick_highlight::highlighted_card(&game), Some(test_games::card("5C")));
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    assert_eq!(trick_highlight::highlighted_card(&game), None);
+}
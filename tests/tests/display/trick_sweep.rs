@@ -0,0 +1,26 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_display::trick_sweep;
+use primitives::HandIdentifier;
+
+#[test]
+fn sweep_targets_winning_seat_anchor() {
+    let size = Vec2::new(800.0, 600.0);
+    assert_eq!(trick_sweep::sweep_target(HandIdentifier::North, size), Vec2::new(0.0, 300.0));
+    assert_eq!(trick_sweep::sweep_target(HandIdentifier::East, size), Vec2::new(400.0, 0.0));
+    assert_eq!(trick_sweep::sweep_target(HandIdentifier::South, size), Vec2::new(0.0, -300.0));
+    assert_eq!(trick_sweep::sweep_target(HandIdentifier::West, size), Vec2::new(-400.0, 0.0));
+}
@@ -0,0 +1,76 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::play_phase_events::PlayPhaseUpdateEvent;
+use play_phase_display::trump_override::{self, SetTrumpEvent};
+use play_phase_rules::play_phase_queries;
+use play_phase_rules::play_phase_review::PlayPhaseReview;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn changing_trump_changes_trick_winner() {
+    let mut app = app(test_games::game("2C 2D", "3H 3D", "4C 4D", "5C 5D"));
+    app.world.send_event(SetTrumpEvent(Some(Suit::Hearts)));
+    app.update();
+    assert_eq!(app.world.resource::<PlayPhaseData>().trump(), Some(Suit::Hearts));
+
+    let game = app.world.resource_mut::<PlayPhaseData>().into_inner();
+    test_games::play(game, HandIdentifier::South, "4C");
+    test_games::play(game, HandIdentifier::West, "5C");
+    test_games::play(game, HandIdentifier::North, "2C");
+    test_games::play(game, HandIdentifier::East, "3H");
+    assert_eq!(
+        play_phase_queries::trick_winner(&game.current_trick, Some(Suit::Spades)),
+        HandIdentifier::West
+    );
+    assert_eq!(
+        play_phase_queries::trick_winner(&game.current_trick, game.trump()),
+        HandIdentifier::East
+    );
+}
+
+#[test]
+fn trump_change_after_opening_lead_is_ignored() {
+    let mut game = test_games::game("2C 2D", "3H 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::West, "5D");
+
+    let mut app = app(game);
+    app.world.send_event(SetTrumpEvent(Some(Suit::Hearts)));
+    app.update();
+
+    let game = app.world.resource_mut::<PlayPhaseData>().into_inner();
+    assert_eq!(game.trump(), Some(Suit::Spades));
+    assert_eq!(game.completed_tricks[0].winner, HandIdentifier::West);
+    test_games::play(game, HandIdentifier::North, "2D");
+    let mut review = PlayPhaseReview::new(game);
+    while review.next_step().is_some() {}
+    assert_eq!(review.current(), *game);
+}
+
+fn app(game: PlayPhaseData) -> App {
+    let mut app = App::new();
+    app.insert_resource(game)
+        .add_event::<SetTrumpEvent>()
+        .add_event::<PlayPhaseUpdateEvent>()
+        .add_systems(Update, trump_override::set_trump);
+    app
+}
@@ -0,0 +1,84 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use play_phase_data::PlayPhaseData;
+use play_phase_display::game_settings::GameSettings;
+use play_phase_display::turn_timer::{self, TurnTimer};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn accumulates_time_for_next_to_play() {
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default())
+        .init_resource::<GameSettings>()
+        .init_resource::<TurnTimer>()
+        .insert_resource(test_games::game("2C", "3C", "4C", "5C"))
+        .add_systems(Update, turn_timer::update);
+
+    advance(&mut app, 500);
+    advance(&mut app, 250);
+    let mut game = app.world.resource_mut::<PlayPhaseData>();
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    advance(&mut app, 100);
+
+    let timer = app.world.resource::<TurnTimer>();
+    assert_eq!(timer.total(HandIdentifier::South), Duration::from_millis(750));
+    assert_eq!(timer.total(HandIdentifier::West), Duration::from_millis(100));
+    assert_eq!(timer.total(HandIdentifier::North), Duration::ZERO);
+}
+
+#[test]
+fn credits_trick_winner_while_trick_is_shown() {
+    let mut app = app_with_complete_trick(GameSettings::default());
+    advance(&mut app, 500);
+    advance(&mut app, 250);
+    assert_eq!(
+        app.world.resource::<TurnTimer>().total(HandIdentifier::West),
+        Duration::from_millis(750)
+    );
+}
+
+#[test]
+fn paused_while_auto_continue_clears_trick() {
+    let mut app = app_with_complete_trick(GameSettings { auto_continue: true, ..default() });
+    advance(&mut app, 500);
+    assert_eq!(app.world.resource::<TurnTimer>().total(HandIdentifier::West), Duration::ZERO);
+}
+
+/// Returns an app in which West has just won the first trick.
+fn app_with_complete_trick(settings: GameSettings) -> App {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default())
+        .insert_resource(settings)
+        .init_resource::<TurnTimer>()
+        .insert_resource(game)
+        .add_systems(Update, turn_timer::update);
+    app
+}
+
+fn advance(app: &mut App, millis: u64) {
+    app.world.resource_mut::<Time>().advance_by(Duration::from_millis(millis));
+    app.update();
+}
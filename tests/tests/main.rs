@@ -12,4 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod auction_phase;
 pub mod core;
+pub mod display;
+pub mod pbn;
+pub mod play_phase;
+pub mod test_games;
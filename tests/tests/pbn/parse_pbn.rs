@@ -0,0 +1,48 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+const PBN: &str = r#"
+[Event "Club Game"]
+[Board "1"]
+[Dealer "N"]
+[Deal "W:KQ85.A2.K73.QJ94 AJ3.KQJT.Q82.K76 T976.98.AJ54.A83 42.76543.T96.T52"]
+"#;
+
+#[test]
+fn parse_single_deal() {
+    let games = pbn::parse_pbn(PBN).unwrap();
+    assert_eq!(games.len(), 1);
+    let game = &games[0];
+    assert!(auction_phase_mutations::is_complete_deal(&game.hands));
+    assert!(game.hands[&HandIdentifier::West].contains(&test_games::card("KS")));
+    assert!(game.hands[&HandIdentifier::North].contains(&test_games::card("10H")));
+    assert!(game.hands[&HandIdentifier::East].contains(&test_games::card("AD")));
+    assert!(game.hands[&HandIdentifier::South].contains(&test_games::card("7H")));
+}
+
+#[test]
+fn parse_invalid_deal() {
+    assert!(pbn::parse_pbn(r#"[Deal "N:AKQ.JT9.876.5432"]"#).is_err());
+    assert!(pbn::parse_pbn(r#"[Deal "X:- - - -"]"#).is_err());
+}
+
+#[test]
+fn pbn_import_rejects_duplicate_card() {
+    let deal = "N:AJ3.KQJT.Q82.K76 T976.98.AJ54.A83 42.76543.T96.T52 KQ85.A2.K73.QJ9A";
+    assert!(pbn::parse_deal(deal).is_err());
+}
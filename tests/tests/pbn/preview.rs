@@ -0,0 +1,30 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+
+#[test]
+fn preview_contains_all_cards() {
+    let game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(9), ..GameConfig::default() });
+    let preview = pbn::preview(&game);
+    let lines = preview.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("N "));
+
+    let hands = lines.iter().map(|line| &line[2..]).collect::<Vec<_>>().join(" ");
+    let deal = pbn::parse_deal(&format!("N:{hands}")).unwrap();
+    assert!(auction_phase_mutations::is_complete_deal(&deal));
+    assert_eq!(deal, game.hands);
+}
@@ -0,0 +1,70 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::{Contract, MIN_BID};
+use primitives::{HandIdentifier, PlayerName, Suit};
+
+use crate::test_games;
+
+const DEAL: &str = "N:AJ3.KQJT.Q82.K76 T976.98.AJ54.A83 42.76543.T96.T52 KQ85.A2.K73.QJ94";
+
+#[test]
+fn deal_round_trip() {
+    let games = pbn::parse_pbn(&format!("[Deal \"{DEAL}\"]")).unwrap();
+    let exported = pbn::to_pbn(&games[0]);
+    assert!(exported.contains(&format!("[Deal \"{DEAL}\"]")));
+    assert_eq!(pbn::parse_pbn(&exported).unwrap()[0].hands, games[0].hands);
+}
+
+#[test]
+fn export_contract() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let exported = pbn::to_pbn(&game);
+    assert!(exported.contains("[Declarer \"S\"]"));
+    assert!(exported.contains("[Contract \"2S\"]"));
+}
+
+#[test]
+fn contract_round_trip_at_minimum_bid() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    game.contract = Contract::new(PlayerName::Opponent, None, MIN_BID).unwrap();
+    let exported = pbn::to_pbn(&game);
+    assert!(exported.contains("[Declarer \"W\"]"));
+    assert!(exported.contains("[Contract \"1NT\"]"));
+    assert_eq!(pbn::parse_contract("1NT", "W").unwrap(), game.contract);
+}
+
+#[test]
+fn parse_contract_rejects_invalid_levels() {
+    assert!(pbn::parse_contract("0S", "N").is_err());
+    assert!(pbn::parse_contract("8H", "N").is_err());
+    assert!(pbn::parse_contract("Pass", "N").is_err());
+}
+
+#[test]
+fn export_includes_played_cards() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(pbn::to_pbn(&game).contains("[Deal \"N:...2 ...3 ...4 ...5\"]"));
+}
+
+#[test]
+fn contract_round_trip_through_import() {
+    let mut game = pbn::parse_pbn(&format!("[Deal \"{DEAL}\"]")).unwrap().remove(0);
+    game.contract = Contract::new(PlayerName::Opponent, Some(Suit::Hearts), 10).unwrap();
+    let imported = pbn::parse_pbn(&pbn::to_pbn(&game)).unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(imported[0].contract, game.contract);
+    assert_eq!(imported[0].hands, game.hands);
+}
@@ -0,0 +1,31 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_debug;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn shows_hands_and_trick() {
+    let mut game = test_games::game("2C 3D", "3C 4D", "4C 5D", "5C 6D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    let board = play_phase_debug::ascii_board(&game);
+    let lines = board.lines().collect::<Vec<_>>();
+    assert!(lines[0].contains("North: 2♣ 3♦"));
+    assert!(lines[1].contains("West: 5♣ 6♦"));
+    assert!(lines[1].contains("East: 3♣ 4♦"));
+    assert!(lines[2].contains("Trick: 4♣ (South)"));
+    assert!(lines[3].contains("South: 5♦"));
+}
@@ -0,0 +1,47 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_flags;
+use primitives::HandIdentifier;
+
+use crate::test_games::{self, cards};
+
+#[test]
+fn any_card_can_lead() {
+    let game = test_games::game("2C", "3C", "4C 5H 6D 7S", "5C");
+    for card in cards("4C 5H 6D 7S") {
+        assert!(play_phase_flags::can_play_card(&game, HandIdentifier::South, card));
+    }
+}
+
+#[test]
+fn void_hand_can_play_any_card() {
+    let mut game = test_games::game("2C", "3C", "4C", "5H 6D 7S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    for card in cards("5H 6D 7S") {
+        assert!(play_phase_flags::can_play_card(&game, HandIdentifier::West, card));
+    }
+}
+
+#[test]
+fn hand_able_to_follow_must_follow() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C 9C 6D 7S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    for card in cards("5C 9C") {
+        assert!(play_phase_flags::can_play_card(&game, HandIdentifier::West, card));
+    }
+    for card in cards("6D 7S") {
+        assert!(!play_phase_flags::can_play_card(&game, HandIdentifier::West, card));
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn can_ruff_when_void_with_trumps() {
+    let game = test_games::game("", "", "2S 3H", "");
+    assert!(play_phase_queries::can_ruff(&game, HandIdentifier::South, Suit::Clubs));
+}
+
+#[test]
+fn cannot_ruff_when_holding_suit() {
+    let game = test_games::game("", "", "2S 3H", "");
+    assert!(!play_phase_queries::can_ruff(&game, HandIdentifier::South, Suit::Hearts));
+}
+
+#[test]
+fn cannot_ruff_without_trumps() {
+    let game = test_games::game("", "", "2D 3H", "");
+    assert!(!play_phase_queries::can_ruff(&game, HandIdentifier::South, Suit::Clubs));
+}
+
+#[test]
+fn cannot_ruff_in_no_trump() {
+    let mut game = test_games::game("", "", "2S 3H", "");
+    game.contract.trump = None;
+    assert!(!play_phase_queries::can_ruff(&game, HandIdentifier::South, Suit::Clubs));
+}
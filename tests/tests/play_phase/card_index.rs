@@ -0,0 +1,46 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn card_at_uses_sorted_order() {
+    let game = test_games::game("", "", "AS 2C 9H KC", "");
+    assert_eq!(game.card_at(HandIdentifier::South, 0), Some(test_games::card("2C")));
+    assert_eq!(game.card_at(HandIdentifier::South, 1), Some(test_games::card("KC")));
+    assert_eq!(game.card_at(HandIdentifier::South, 3), Some(test_games::card("AS")));
+    assert_eq!(game.card_at(HandIdentifier::South, 4), None);
+}
+
+#[test]
+fn card_index_round_trip() {
+    let game = test_games::game("", "", "AS 2C 9H KC", "");
+    for index in 0..4 {
+        let card = game.card_at(HandIdentifier::South, index).unwrap();
+        assert_eq!(game.index_of(HandIdentifier::South, card), Some(index));
+    }
+    assert_eq!(game.index_of(HandIdentifier::South, test_games::card("3D")), None);
+}
+
+#[test]
+fn sorted_hand_matches_hand_set() {
+    let game = test_games::game("", "", "AS 2C 9H KC", "");
+    let sorted = game.sorted_hand(HandIdentifier::South);
+    assert_eq!(sorted, test_games::cards("2C KC 9H AS"));
+    assert!(sorted.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(sorted.len(), game.hands[&HandIdentifier::South].len());
+    assert!(sorted.iter().all(|card| game.hands[&HandIdentifier::South].contains(card)));
+}
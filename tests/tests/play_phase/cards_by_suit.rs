@@ -0,0 +1,27 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn cards_by_suit() {
+    let game = test_games::game("", "", "KC 4H 2C AC 9S 7C", "");
+    let groups = game.cards_by_suit(HandIdentifier::South);
+    assert_eq!(groups[&Suit::Clubs], test_games::cards("2C 7C KC AC"));
+    assert_eq!(groups[&Suit::Diamonds], vec![]);
+    assert_eq!(groups[&Suit::Hearts], test_games::cards("4H"));
+    assert_eq!(groups[&Suit::Spades], test_games::cards("9S"));
+}
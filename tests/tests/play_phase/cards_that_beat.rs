@@ -0,0 +1,63 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn low_lead_in_trump_contract() {
+    let game = test_games::game("AH 4S", "2S KH 2H AD", "JH", "QS 5C");
+    assert_eq!(
+        play_phase_queries::cards_that_beat(&game, test_games::card("JH")),
+        test_games::cards("QH KH 2S 3S 5S 6S 7S 8S 9S 10S JS QS KS AS")
+    );
+}
+
+#[test]
+fn trump_lead_in_trump_contract() {
+    let game = test_games::game("AS", "2S KH", "9S", "QS AD");
+    assert_eq!(
+        play_phase_queries::cards_that_beat(&game, test_games::card("9S")),
+        test_games::cards("10S JS QS KS")
+    );
+}
+
+#[test]
+fn played_cards_are_not_unseen() {
+    let mut game = test_games::game("AS 2C", "2S 3C", "9S 4C", "QS 5C");
+    game.contract.trump = None;
+    test_games::play(&mut game, HandIdentifier::South, "9S");
+    test_games::play(&mut game, HandIdentifier::West, "QS");
+    test_games::play(&mut game, HandIdentifier::North, "AS");
+    test_games::play(&mut game, HandIdentifier::East, "2S");
+    assert_eq!(
+        play_phase_queries::cards_that_beat(&game, test_games::card("9S")),
+        test_games::cards("10S JS KS")
+    );
+}
+
+#[test]
+fn trick_winner_respects_trump() {
+    let mut game = test_games::game("2C", "2S", "AC", "KC");
+    test_games::play(&mut game, HandIdentifier::South, "AC");
+    test_games::play(&mut game, HandIdentifier::West, "KC");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "2S");
+    assert_eq!(
+        play_phase_queries::trick_winner(&game.current_trick, game.contract.trump),
+        HandIdentifier::East
+    );
+}
@@ -0,0 +1,56 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{HandIdentifier, PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn combined_hand() {
+    let game = test_games::game("AC 2H", "3C", "KC 5S 6S", "4D");
+    assert_eq!(game.combined_hand(PlayerName::User), test_games::cards("KC AC 2H 5S 6S"));
+    assert_eq!(game.combined_hand(PlayerName::Opponent), test_games::cards("3C 4D"));
+}
+
+#[test]
+fn combined_suit_counts() {
+    let game = test_games::game("AC 2H 9S", "3C", "KC QC 5S 6S", "4D");
+    let counts = game.combined_suit_counts(PlayerName::User);
+    assert_eq!(counts[&Suit::Clubs], 3);
+    assert_eq!(counts[&Suit::Hearts], 1);
+    assert_eq!(counts[&Suit::Spades], 3);
+    assert_eq!(counts[&Suit::Diamonds], 0);
+}
+
+#[test]
+fn trump_length_sums_both_hands() {
+    let game = test_games::game("AS 9S 2H", "3S", "KS QS 5S 6C", "4D");
+    assert_eq!(game.trump_length(PlayerName::User), 5);
+    assert_eq!(game.trump_length(PlayerName::Opponent), 1);
+}
+
+#[test]
+fn no_trump_length_in_no_trump() {
+    let mut game = test_games::game("AS 9S 2H", "3S", "KS QS 5S 6C", "4D");
+    game.contract.trump = None;
+    assert_eq!(game.trump_length(PlayerName::User), 0);
+}
+
+#[test]
+fn declaring_side_points() {
+    let mut game = test_games::game("AS KH 2C", "QS 3C", "QD JC 4C", "AC");
+    assert_eq!(game.declaring_side_points(), 10);
+    test_games::play(&mut game, HandIdentifier::South, "JC");
+    assert_eq!(game.declaring_side_points(), 10);
+}
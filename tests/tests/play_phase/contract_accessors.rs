@@ -0,0 +1,29 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn accessors_mirror_contract() {
+    let mut game = test_games::game("", "", "", "");
+    assert_eq!(game.trump(), Some(Suit::Spades));
+    assert_eq!(game.declarer(), PlayerName::User);
+
+    game.contract.trump = None;
+    game.contract.declarer = PlayerName::Opponent;
+    assert_eq!(game.trump(), None);
+    assert_eq!(game.declarer(), PlayerName::Opponent);
+}
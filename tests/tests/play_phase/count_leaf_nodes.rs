@@ -0,0 +1,46 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+
+use crate::test_games;
+
+#[test]
+fn single_card_hands_have_one_leaf() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(play_phase_analysis::count_leaf_nodes(&game), 1);
+}
+
+#[test]
+fn forced_follows_multiply_lead_choices() {
+    // South chooses either lead and the other hands must follow suit.
+    let game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    assert_eq!(play_phase_analysis::count_leaf_nodes(&game), 2);
+}
+
+#[test]
+fn void_hand_may_discard_either_card() {
+    // South chooses either lead and West, void in both suits, may discard
+    // either diamond. South wins the first trick, after which every play is
+    // forced.
+    let game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5D 6D");
+    assert_eq!(play_phase_analysis::count_leaf_nodes(&game), 4);
+}
+
+#[test]
+fn finished_game_has_one_leaf() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    test_games::play_out(&mut game);
+    assert_eq!(play_phase_analysis::count_leaf_nodes(&game), 1);
+}
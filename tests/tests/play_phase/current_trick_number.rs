@@ -0,0 +1,35 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn first_trick_at_start() {
+    let game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    assert_eq!(game.current_trick_number(), 1);
+}
+
+#[test]
+fn advances_when_next_trick_is_led() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(game.current_trick_number(), 1);
+    test_games::play(&mut game, HandIdentifier::West, "5D");
+    assert_eq!(game.current_trick_number(), 2);
+}
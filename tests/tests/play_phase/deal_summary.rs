@@ -0,0 +1,50 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+use play_phase_rules::play_phase_analysis::DealSummary;
+use primitives::{HandIdentifier, PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn summarizes_seeded_deal() {
+    let mut game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(3), ..GameConfig::default() });
+    let before = DealSummary::analyze(&game);
+    test_games::play_out(&mut game);
+    let summary = DealSummary::analyze(&game);
+    assert_eq!(summary, before);
+
+    assert_eq!(summary.hcp[&HandIdentifier::North], 15);
+    assert_eq!(summary.hcp[&HandIdentifier::East], 7);
+    assert_eq!(summary.hcp[&HandIdentifier::South], 8);
+    assert_eq!(summary.hcp[&HandIdentifier::West], 10);
+    let west = &summary.shapes[&HandIdentifier::West];
+    let shape = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs].map(|suit| west[&suit]);
+    assert_eq!(shape, [5, 2, 5, 1]);
+    assert_eq!(summary.longest_fit[&PlayerName::User], (Suit::Hearts, 8));
+    assert_eq!(summary.longest_fit[&PlayerName::Opponent], (Suit::Spades, 8));
+}
+
+#[test]
+fn longest_fit_of_constructed_deal() {
+    let game = test_games::game("2H 3H 4H AC", "2C 3C 4C 5C", "5H 6H 2S KD", "7H 8H 3S 2D");
+    let summary = DealSummary::analyze(&game);
+    assert_eq!(summary.longest_fit[&PlayerName::User], (Suit::Hearts, 5));
+    assert_eq!(summary.longest_fit[&PlayerName::Opponent], (Suit::Clubs, 4));
+    assert_eq!(summary.hcp[&HandIdentifier::North], 4);
+    assert_eq!(summary.hcp[&HandIdentifier::South], 3);
+    assert_eq!(summary.shapes[&HandIdentifier::East][&Suit::Clubs], 4);
+}
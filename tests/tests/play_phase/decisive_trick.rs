@@ -0,0 +1,76 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_analysis;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+/// Plays a three-trick game in which West wins every trick.
+fn scripted_game(bid: u32) -> PlayPhaseData {
+    let mut game = test_games::game("2C 2H 2D", "3C 3H 3D", "4C 4H 4D", "AC 5H 5D");
+    game.contract.bid = bid;
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "AC");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "4H");
+    test_games::play(&mut game, HandIdentifier::West, "5D");
+    test_games::play(&mut game, HandIdentifier::North, "2D");
+    test_games::play(&mut game, HandIdentifier::East, "3D");
+    test_games::play(&mut game, HandIdentifier::South, "4D");
+    game
+}
+
+#[test]
+fn trick_winners_include_final_trick() {
+    let game = scripted_game(1);
+    assert_eq!(play_phase_analysis::trick_winners(&game), vec![HandIdentifier::West; 3]);
+}
+
+#[test]
+fn contract_defeated_on_second_trick() {
+    assert_eq!(play_phase_analysis::decisive_trick(&scripted_game(2)), Some(1));
+}
+
+#[test]
+fn contract_defeated_on_first_trick() {
+    assert_eq!(play_phase_analysis::decisive_trick(&scripted_game(3)), Some(0));
+}
+
+#[test]
+fn contract_defeated_on_last_trick() {
+    assert_eq!(play_phase_analysis::decisive_trick(&scripted_game(1)), Some(2));
+}
+
+#[test]
+fn contract_made_on_first_trick() {
+    let mut game = test_games::game("2C", "3C", "AC", "4C");
+    game.contract.bid = 1;
+    test_games::play(&mut game, HandIdentifier::South, "AC");
+    test_games::play(&mut game, HandIdentifier::West, "4C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(play_phase_analysis::decisive_trick(&game), Some(0));
+}
+
+#[test]
+fn undecided_before_play() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(play_phase_analysis::decisive_trick(&game), None);
+}
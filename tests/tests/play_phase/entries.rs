@@ -0,0 +1,36 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn counts_top_winners_in_target_hand() {
+    let game = test_games::game("AH KH 2C", "QH 3C 4C", "3H 4H 5C", "JH 6C 7C");
+    assert_eq!(play_phase_queries::entries(&game, HandIdentifier::South, HandIdentifier::North), 2);
+}
+
+#[test]
+fn entries_limited_by_cards_to_lead() {
+    let game = test_games::game("AH KH AC", "QH 3C 4C", "3H 5D 5C", "JH 6C 7C");
+    assert_eq!(play_phase_queries::entries(&game, HandIdentifier::South, HandIdentifier::North), 2);
+}
+
+#[test]
+fn no_entries_without_winners() {
+    let game = test_games::game("KH 2H 2C", "AH 3C 4C", "3H 4H 5C", "JH 6C 7C");
+    assert_eq!(play_phase_queries::entries(&game, HandIdentifier::South, HandIdentifier::North), 0);
+}
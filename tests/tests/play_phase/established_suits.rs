@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn suit_established_after_ace_is_gone() {
+    let mut game = test_games::game("2H 3C", "3H 4C", "KH QH JH 2C", "AH 5H 5C");
+    assert!(play_phase_queries::established_suits(&game, HandIdentifier::South).is_empty());
+
+    test_games::play(&mut game, HandIdentifier::South, "KH");
+    test_games::play(&mut game, HandIdentifier::West, "AH");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    assert_eq!(
+        play_phase_queries::established_suits(&game, HandIdentifier::South),
+        vec![Suit::Hearts]
+    );
+}
+
+#[test]
+fn low_cards_are_not_established() {
+    let game = test_games::game("", "3C", "AC 2C", "");
+    assert!(play_phase_queries::established_suits(&game, HandIdentifier::South).is_empty());
+}
@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn lead_toward_ace_queen() {
+    let game = test_games::game("AH QH 2C", "KH 3C", "3H 5H 4C", "4H 5C");
+    assert_eq!(
+        play_phase_queries::finesse_candidates(&game, HandIdentifier::South),
+        vec![test_games::card("3H")]
+    );
+}
+
+#[test]
+fn no_finesse_when_king_held_by_partnership() {
+    let game = test_games::game("AH QH 2C", "4H 3C", "3H KH 4C", "5H 5C");
+    assert!(play_phase_queries::finesse_candidates(&game, HandIdentifier::South).is_empty());
+}
+
+#[test]
+fn no_finesse_when_not_on_lead() {
+    let game = test_games::game("3H 2C", "KH 3C", "AH QH 4C", "4H 5C");
+    assert!(play_phase_queries::finesse_candidates(&game, HandIdentifier::North).is_empty());
+}
@@ -0,0 +1,59 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseAction;
+use play_phase_rules::{play_phase_actions, play_phase_queries};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn singleton_follow_is_forced() {
+    let mut game = test_games::game("2C 3C", "4D 5C", "6C 7H", "8C 9H");
+    test_games::play(&mut game, HandIdentifier::South, "6C");
+    assert_eq!(
+        play_phase_queries::forced_play(&game),
+        Some((HandIdentifier::West, test_games::card("8C")))
+    );
+}
+
+#[test]
+fn choice_of_follows_is_not_forced() {
+    let mut game = test_games::game("2C 3C", "4D 5C", "6C 7H", "8C 9H");
+    test_games::play(&mut game, HandIdentifier::South, "6C");
+    test_games::play(&mut game, HandIdentifier::West, "8C");
+    assert_eq!(play_phase_queries::legal_plays(&game, HandIdentifier::North).len(), 2);
+    assert_eq!(play_phase_queries::forced_play(&game), None);
+}
+
+#[test]
+fn lead_is_not_forced() {
+    let game = test_games::game("2C 3C", "4D 5C", "6C 7H", "8C 9H");
+    assert_eq!(play_phase_queries::forced_play(&game), None);
+}
+
+#[test]
+fn nothing_forced_after_game_over() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    let mut plays = 0;
+    while let Some((hand, card)) = play_phase_queries::forced_play(&game) {
+        play_phase_actions::handle_action(
+            &mut game,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+        plays += 1;
+    }
+    assert_eq!(plays, 4);
+    assert!(game.is_game_over());
+}
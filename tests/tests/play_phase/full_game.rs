@@ -0,0 +1,49 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+use play_phase_data::PlayPhaseAction;
+use play_phase_rules::{
+    play_phase_actions, play_phase_analysis, play_phase_flags, play_phase_queries,
+};
+/// Deals a seeded game and plays it to the end using only validated actions,
+/// continuing to each new trick explicitly.
+#[test]
+fn deal_and_play_every_trick() {
+    let mut game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(11), ..GameConfig::default() });
+    let mut actions = 0;
+    while !game.is_game_over() {
+        let action = if play_phase_flags::can_continue_to_next_trick(&game) {
+            PlayPhaseAction::ContinueToNextTrick
+        } else {
+            let hand = play_phase_queries::next_to_play(&game);
+            let card = play_phase_queries::legal_plays(&game, hand)[0];
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card)
+        };
+        play_phase_actions::try_handle_action(&mut game, action).unwrap();
+        actions += 1;
+    }
+
+    assert_eq!(actions, 52 + 12);
+    assert!(game.hands.values().all(|hand| hand.is_empty()));
+    assert!(game.verify_complete().is_ok());
+
+    let winners = play_phase_analysis::trick_winners(&game);
+    assert_eq!(winners.len(), play_phase_analysis::TRICKS_PER_GAME);
+    let declarer_tricks =
+        winners.iter().filter(|winner| winner.player_name() == game.declarer()).count();
+    let result = game.winning_side(declarer_tricks);
+    assert_eq!(result == game.declarer(), declarer_tricks >= game.contract.bid as usize);
+}
@@ -0,0 +1,44 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use game_archive::{GameArchive, GameId};
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn save_and_load_game() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    let mut archive = GameArchive::default();
+    let id = archive.save(&game).unwrap();
+    assert_eq!(archive.load(id), Some(game));
+}
+
+#[test]
+fn ids_are_unique() {
+    let mut archive = GameArchive::default();
+    let first = archive.save(&test_games::game("2C", "3C", "4C", "5C")).unwrap();
+    let second = archive.save(&test_games::game("2H", "3H", "4H", "5H")).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(archive.ids(), vec![first, second]);
+    assert!(archive.load(first).unwrap().hands[&HandIdentifier::North]
+        .contains(&test_games::card("2C")));
+}
+
+#[test]
+fn missing_game_is_none() {
+    assert_eq!(GameArchive::default().load(GameId(7)), None);
+}
@@ -0,0 +1,58 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::GameEvent;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn winning_trump_emits_ruffed() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "2S 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "2S");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(
+        game.events,
+        vec![
+            GameEvent::Ruffed(HandIdentifier::West, Suit::Clubs),
+            GameEvent::TrickWon(HandIdentifier::West)
+        ]
+    );
+}
+
+#[test]
+fn final_trick_emits_contract_defeated() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(game.events.last(), Some(&GameEvent::ContractDefeated));
+}
+
+#[test]
+fn final_trick_emits_contract_made() {
+    let mut game = test_games::game("2C", "3C", "AC", "5C");
+    game.contract.bid = 1;
+    test_games::play(&mut game, HandIdentifier::South, "AC");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(
+        game.events,
+        vec![GameEvent::TrickWon(HandIdentifier::South), GameEvent::ContractMade]
+    );
+}
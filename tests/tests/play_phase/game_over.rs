@@ -0,0 +1,34 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayedCard;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn empty_game_is_over() {
+    let game = test_games::game("", "", "", "");
+    assert!(game.is_game_over());
+}
+
+#[test]
+fn game_with_cards_remaining_is_not_over() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    game.hands.get_mut(&HandIdentifier::South).unwrap().remove(&test_games::card("4C"));
+    game.current_trick
+        .cards
+        .push(PlayedCard { played_by: HandIdentifier::South, card: test_games::card("4C") });
+    assert!(!game.is_game_over());
+}
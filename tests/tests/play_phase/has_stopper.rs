@@ -0,0 +1,42 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn guarded_king_is_stopper() {
+    let game = test_games::game("2C 3C", "AH 4C", "KH 3H", "5C 6C");
+    assert!(play_phase_queries::has_stopper(&game, HandIdentifier::South, Suit::Hearts));
+}
+
+#[test]
+fn unguarded_king_is_not_stopper() {
+    let game = test_games::game("2C 3C", "AH 4C", "KH 4D", "5C 6C");
+    assert!(!play_phase_queries::has_stopper(&game, HandIdentifier::South, Suit::Hearts));
+}
+
+#[test]
+fn highest_outstanding_card_is_stopper() {
+    let game = test_games::game("2C 3C", "8H 4C", "9H 4D", "5C 6C");
+    assert!(play_phase_queries::has_stopper(&game, HandIdentifier::South, Suit::Hearts));
+}
+
+#[test]
+fn void_hand_has_no_stopper() {
+    let game = test_games::game("2C 3C", "8H 4C", "4D 5D", "5C 6C");
+    assert!(!play_phase_queries::has_stopper(&game, HandIdentifier::South, Suit::Hearts));
+}
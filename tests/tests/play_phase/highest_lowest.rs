@@ -0,0 +1,51 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn highest_and_lowest_clubs() {
+    let game = test_games::game("", "", "2C 9C AC 5C KH", "");
+    let south = HandIdentifier::South;
+    assert_eq!(
+        play_phase_queries::lowest_of_suit(&game, south, Suit::Clubs),
+        Some(test_games::card("2C"))
+    );
+    assert_eq!(
+        play_phase_queries::highest_of_suit(&game, south, Suit::Clubs),
+        Some(test_games::card("AC"))
+    );
+}
+
+#[test]
+fn void_suit_has_no_highest_or_lowest() {
+    let game = test_games::game("", "", "2C", "");
+    let south = HandIdentifier::South;
+    assert_eq!(play_phase_queries::highest_of_suit(&game, south, Suit::Spades), None);
+    assert_eq!(play_phase_queries::lowest_of_suit(&game, south, Suit::Spades), None);
+}
+
+#[test]
+fn clubs_in_descending_order() {
+    let game = test_games::game("", "", "2C 9C AC 5C KH", "");
+    assert_eq!(
+        play_phase_queries::cards_of_suit_desc(&game, HandIdentifier::South, Suit::Clubs),
+        test_games::cards("AC 9C 5C 2C")
+    );
+    assert!(play_phase_queries::cards_of_suit_desc(&game, HandIdentifier::South, Suit::Spades)
+        .is_empty());
+}
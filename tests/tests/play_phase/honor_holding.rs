@@ -0,0 +1,37 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn honors_with_small_card() {
+    let game = test_games::game("AH 2C", "QH 3C", "KH 5H", "JH 4C");
+    assert_eq!(play_phase_queries::honor_holding(&game, PlayerName::User, Suit::Hearts), "AKx");
+}
+
+#[test]
+fn void_suit_is_empty() {
+    let game = test_games::game("AH 2C", "QH 3C", "KH 5H", "JH 4C");
+    assert_eq!(play_phase_queries::honor_holding(&game, PlayerName::User, Suit::Spades), "");
+}
+
+#[test]
+fn opponent_holding() {
+    let game = test_games::game("AH 2C", "QH 3C", "KH 5H", "JH 4C");
+    assert_eq!(play_phase_queries::honor_holding(&game, PlayerName::Opponent, Suit::Hearts), "QJ");
+    assert_eq!(play_phase_queries::honor_holding(&game, PlayerName::Opponent, Suit::Clubs), "xx");
+}
@@ -0,0 +1,44 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+use primitives::{HandIdentifier, Rank, Suit};
+
+use crate::test_games;
+
+#[test]
+fn missing_king_is_even_odds() {
+    let game = test_games::game("2S 3S 4S", "KS 5H 6H", "AS QS 7H", "5S 6S 8H");
+    let probability =
+        play_phase_analysis::honor_onside_probability(&game, Suit::Spades, Rank::King);
+    assert!((probability - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn defender_showing_void_cannot_hold_honor() {
+    let mut game = test_games::game("2S 3S 4S", "KS 5H 6H", "AS QS 7H", "5H 7D 8H");
+    test_games::play(&mut game, HandIdentifier::South, "QS");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    let probability =
+        play_phase_analysis::honor_onside_probability(&game, Suit::Spades, Rank::King);
+    assert_eq!(probability, 1.0);
+}
+
+#[test]
+fn honor_held_by_declaring_side_is_not_missing() {
+    let game = test_games::game("KS 3S 4S", "2S 5H 6H", "AS QS 7H", "5S 6S 8H");
+    let probability =
+        play_phase_analysis::honor_onside_probability(&game, Suit::Spades, Rank::King);
+    assert_eq!(probability, 0.0);
+}
@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn no_safe_exit_is_endplayed() {
+    let mut game = test_games::game("2C 3C", "AH QH", "KH 4C", "5C JH");
+    game.contract.trump = None;
+    assert!(play_phase_analysis::is_endplayed(&game, HandIdentifier::South));
+}
+
+#[test]
+fn safe_exit_is_not_endplayed() {
+    let mut game = test_games::game("2C 3C", "AH QH", "KH AC", "5C JH");
+    game.contract.trump = None;
+    assert!(!play_phase_analysis::is_endplayed(&game, HandIdentifier::South));
+}
+
+#[test]
+fn hand_not_on_lead_is_not_endplayed() {
+    let mut game = test_games::game("2C 3C", "AH QH", "KH 4C", "5C JH");
+    game.contract.trump = None;
+    assert!(!play_phase_analysis::is_endplayed(&game, HandIdentifier::West));
+}
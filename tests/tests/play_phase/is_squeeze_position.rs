@@ -0,0 +1,62 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseData;
+use play_phase_rules::play_phase_analysis;
+use primitives::Suit;
+
+use crate::test_games;
+
+/// West must keep KH 3H to guard against North's AH QH, and KD to guard
+/// against South's QD, so cashing the AS forces a fatal discard.
+fn squeeze() -> PlayPhaseData {
+    test_games::game("AH QH 2C", "5C 6C 7C", "AS QD 2H", "KH 3H KD")
+}
+
+#[test]
+fn defender_guarding_two_suits_is_squeezed() {
+    assert!(play_phase_analysis::is_squeeze_position(&squeeze()));
+}
+
+#[test]
+fn squeeze_in_no_trump() {
+    let mut game = squeeze();
+    game.contract.trump = None;
+    assert!(play_phase_analysis::is_squeeze_position(&game));
+}
+
+#[test]
+fn spare_small_card_is_idle() {
+    let game = test_games::game("2S 3S 4S", "5S 6S 7S", "AS QH QD", "KH 2H KD");
+    assert!(!play_phase_analysis::is_squeeze_position(&game));
+}
+
+#[test]
+fn idle_card_avoids_squeeze() {
+    let game = test_games::game("2S 3S 4S", "5S 6S 7S", "AS QH QD", "KH KD 2C");
+    assert!(!play_phase_analysis::is_squeeze_position(&game));
+}
+
+#[test]
+fn shared_guard_avoids_squeeze() {
+    let game = test_games::game("2S 3S 4S", "5S 6S KD", "AS QH QD", "KH 2H AD");
+    assert!(!play_phase_analysis::is_squeeze_position(&game));
+}
+
+#[test]
+fn ruffable_winner_avoids_squeeze() {
+    let mut game = squeeze();
+    game.contract.trump = Some(Suit::Clubs);
+    assert!(!play_phase_analysis::is_squeeze_position(&game));
+}
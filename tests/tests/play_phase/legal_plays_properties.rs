@@ -0,0 +1,40 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::test_games;
+
+#[test]
+fn legal_plays_invariants_hold_for_random_positions() {
+    let mut rng = StdRng::seed_from_u64(2232);
+    for _ in 0..1000 {
+        let game = test_games::random_position(&mut rng);
+        let hand = play_phase_queries::next_to_play(&game);
+        let held = game.hand(hand).collect::<Vec<_>>();
+        let legal = play_phase_queries::legal_plays(&game, hand);
+
+        assert!(!legal.is_empty());
+        assert!(legal.len() <= held.len());
+        assert!(legal.iter().all(|card| held.contains(card)));
+        match play_phase_queries::lead_suit(&game) {
+            Some(suit) if held.iter().any(|card| card.suit == suit) => {
+                assert!(legal.iter().all(|card| card.suit == suit));
+            }
+            _ => assert_eq!(legal.len(), held.len()),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+use primitives::Suit;
+
+use crate::test_games;
+
+#[test]
+fn defenders_longest_in_unseen_suit() {
+    let game =
+        test_games::game("AS KS QS JS 9S AH KH QH JH", "3C 4C", "AD KD QD JD 9D 8D 2C", "5C 6C");
+    assert_eq!(play_phase_analysis::likely_opponent_suit(&game), Some(Suit::Clubs));
+}
+
+#[test]
+fn ties_favor_higher_suit() {
+    let game = test_games::game("2C", "3C", "2D", "4C");
+    assert_eq!(play_phase_analysis::likely_opponent_suit(&game), Some(Suit::Spades));
+}
@@ -0,0 +1,44 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn ten_is_minimum_winner_over_nine() {
+    let mut game = test_games::game("3H", "4H", "9H", "AH 10H 2H 5C");
+    test_games::play(&mut game, HandIdentifier::South, "9H");
+    assert_eq!(
+        play_phase_queries::minimum_winning_card(&game, HandIdentifier::West),
+        Some(test_games::card("10H"))
+    );
+}
+
+#[test]
+fn ruff_when_void_in_suit_led() {
+    let mut game = test_games::game("", "", "9H", "2S 5S AC");
+    test_games::play(&mut game, HandIdentifier::South, "9H");
+    assert_eq!(
+        play_phase_queries::minimum_winning_card(&game, HandIdentifier::West),
+        Some(test_games::card("2S"))
+    );
+}
+
+#[test]
+fn no_winner_before_lead() {
+    let game = test_games::game("", "", "9H", "");
+    assert_eq!(play_phase_queries::minimum_winning_card(&game, HandIdentifier::South), None);
+}
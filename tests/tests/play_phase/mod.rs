@@ -0,0 +1,71 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod ascii_board;
+pub mod can_play_card;
+pub mod can_ruff;
+pub mod card_index;
+pub mod cards_by_suit;
+pub mod cards_that_beat;
+pub mod combined_hands;
+pub mod contract_accessors;
+pub mod count_leaf_nodes;
+pub mod current_trick_number;
+pub mod deal_summary;
+pub mod decisive_trick;
+pub mod entries;
+pub mod established_suits;
+pub mod finesse_candidates;
+pub mod forced_play;
+pub mod full_game;
+pub mod game_archive;
+pub mod game_events;
+pub mod game_over;
+pub mod has_stopper;
+pub mod highest_lowest;
+pub mod honor_holding;
+pub mod honor_onside_probability;
+pub mod is_endplayed;
+pub mod is_squeeze_position;
+pub mod lead_card;
+pub mod legal_plays_properties;
+pub mod likely_opponent_suit;
+pub mod minimum_winning_card;
+pub mod opening_lead;
+pub mod outstanding_trumps;
+pub mod playing_tricks;
+pub mod quick_tricks;
+pub mod ranked_discards;
+pub mod read_signal;
+pub mod recommend_opening_lead;
+pub mod result_screen;
+pub mod review;
+pub mod ruff_count;
+pub mod save_load;
+pub mod score_breakdown;
+pub mod side_winning_trick;
+pub mod sorted_hands;
+pub mod suggested_level;
+pub mod suit_play_counts;
+pub mod swap_partnerships;
+pub mod trick_history;
+pub mod trick_result;
+pub mod trick_still_contestable;
+pub mod trick_winner_properties;
+pub mod tricks_still_needed;
+pub mod try_handle_action;
+pub mod turn_order;
+pub mod verify_complete;
+pub mod visible_suit_cards;
+pub mod winning_side;
@@ -0,0 +1,30 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn opening_lead_before_first_card() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert!(game.is_opening_lead());
+}
+
+#[test]
+fn not_opening_lead_after_first_card() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(!game.is_opening_lead());
+}
@@ -0,0 +1,36 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn outstanding_trumps_after_trumps_played() {
+    let mut game = test_games::game("AS 2C", "3S 3C", "KS QS 4C", "5S 5C");
+    assert_eq!(play_phase_queries::outstanding_trumps(&game), 10);
+    test_games::play(&mut game, HandIdentifier::South, "KS");
+    test_games::play(&mut game, HandIdentifier::West, "5S");
+    test_games::play(&mut game, HandIdentifier::North, "AS");
+    test_games::play(&mut game, HandIdentifier::East, "3S");
+    assert_eq!(play_phase_queries::outstanding_trumps(&game), 8);
+}
+
+#[test]
+fn no_outstanding_trumps_in_no_trump() {
+    let mut game = test_games::game("AS", "3S", "KS", "5S");
+    game.contract.trump = None;
+    assert_eq!(play_phase_queries::outstanding_trumps(&game), 0);
+}
@@ -0,0 +1,42 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use play_phase_rules::play_phase_analysis;
+use primitives::{Card, Suit};
+
+use crate::test_games;
+
+#[test]
+fn long_trump_suit() {
+    let hand = hand("AS KS QS 7S 5S 3S AH 4H KD 2D 8C 6C 4C");
+    assert_eq!(play_phase_analysis::playing_tricks(&hand, Some(Suit::Spades)), 8);
+}
+
+#[test]
+fn same_hand_in_no_trump() {
+    let hand = hand("AS KS QS 7S 5S 3S AH 4H KD 2D 8C 6C 4C");
+    assert_eq!(play_phase_analysis::playing_tricks(&hand, None), 7);
+}
+
+#[test]
+fn unguarded_honors_do_not_count() {
+    assert_eq!(play_phase_analysis::playing_tricks(&hand("KH QD 2D"), None), 0);
+    assert_eq!(play_phase_analysis::playing_tricks(&hand("QH 3H 2H"), None), 1);
+}
+
+fn hand(cards: &str) -> BTreeSet<Card> {
+    test_games::cards(cards).into_iter().collect()
+}
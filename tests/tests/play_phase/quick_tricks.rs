@@ -0,0 +1,44 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use play_phase_rules::play_phase_analysis;
+use primitives::Card;
+
+use crate::test_games;
+
+#[test]
+fn single_suit_holdings() {
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("AS KS 2S")), 2.0);
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("AH QH")), 1.5);
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("AD 5D")), 1.0);
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("KC QC 9C")), 1.0);
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("KS 3S")), 0.5);
+}
+
+#[test]
+fn singleton_king_is_not_a_quick_trick() {
+    assert_eq!(play_phase_analysis::quick_tricks(&hand("KS QH JD")), 0.0);
+}
+
+#[test]
+fn sums_across_suits() {
+    let hand = hand("AS KS QS AH QH 7H KD 4D KC 9C 8C 3C 2C");
+    assert_eq!(play_phase_analysis::quick_tricks(&hand), 4.5);
+}
+
+fn hand(cards: &str) -> BTreeSet<Card> {
+    test_games::cards(cards).into_iter().collect()
+}
@@ -0,0 +1,40 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games::{self, card, cards};
+
+#[test]
+fn keeps_guarded_honor() {
+    let mut game = test_games::game("2C 6D 7D", "3C 8D 9D", "4C 10D JD", "KH 2H 3D 4D 5D 2S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    let discards = play_phase_queries::ranked_discards(&game, HandIdentifier::West);
+    assert_eq!(discards, cards("3D 4D 5D 2H KH"));
+    assert_eq!(discards[0], card("3D"));
+}
+
+#[test]
+fn no_discards_when_following_suit() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C 2H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(play_phase_queries::ranked_discards(&game, HandIdentifier::West).is_empty());
+}
+
+#[test]
+fn no_discards_on_lead() {
+    let game = test_games::game("2C", "3C", "4C 2H", "5C");
+    assert!(play_phase_queries::ranked_discards(&game, HandIdentifier::South).is_empty());
+}
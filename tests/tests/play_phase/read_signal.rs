@@ -0,0 +1,45 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn high_heart_discard_shows_hearts() {
+    let mut game = test_games::game("9H 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "9H");
+    assert_eq!(play_phase_queries::read_signal(&game, HandIdentifier::South), Some(Suit::Hearts));
+}
+
+#[test]
+fn low_discard_shows_no_preference() {
+    let mut game = test_games::game("2H 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    assert_eq!(play_phase_queries::read_signal(&game, HandIdentifier::South), None);
+}
+
+#[test]
+fn following_suit_is_not_a_signal() {
+    let mut game = test_games::game("9C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "9C");
+    assert_eq!(play_phase_queries::read_signal(&game, HandIdentifier::South), None);
+}
@@ -0,0 +1,55 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_coach;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn fourth_highest_against_no_trump() {
+    let mut game = test_games::game("2C", "3C", "4C", "AS 9S 7S 5S 3S KH 2H 4D 5D 6D 7C 8C 9C");
+    game.contract.trump = None;
+    assert_eq!(
+        play_phase_coach::recommend_opening_lead(&game, HandIdentifier::West),
+        test_games::card("5S")
+    );
+}
+
+#[test]
+fn singleton_against_trump_contract() {
+    let game = test_games::game("2C", "3C", "4C", "4D 2S 3S KH QH 5H 9C 8C 7C");
+    assert_eq!(
+        play_phase_coach::recommend_opening_lead(&game, HandIdentifier::West),
+        test_games::card("4D")
+    );
+}
+
+#[test]
+fn sequence_against_trump_contract() {
+    let game = test_games::game("2C", "3C", "4C", "KH QH 5H 9C 8C 2S 3S 7D 6D");
+    assert_eq!(
+        play_phase_coach::recommend_opening_lead(&game, HandIdentifier::West),
+        test_games::card("KH")
+    );
+}
+
+#[test]
+fn singleton_without_trumps_is_not_preferred() {
+    let game = test_games::game("2C", "3C", "4C", "4D KH QH 5H 9C 8C 7C");
+    assert_eq!(
+        play_phase_coach::recommend_opening_lead(&game, HandIdentifier::West),
+        test_games::card("KH")
+    );
+}
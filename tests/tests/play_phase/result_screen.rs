@@ -0,0 +1,69 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::Contract;
+use play_phase_rules::play_phase_result::{self, ResultOption};
+use primitives::{HandIdentifier, PlayerName, Suit};
+
+use crate::test_games;
+
+#[test]
+fn made_contract() {
+    let screen = play_phase_result::build(&contract(), 9);
+    assert_eq!(screen.title, "Contract Made");
+    assert_eq!(screen.summary, "8♠ by You: 9 tricks, made with 1 overtrick");
+    assert!(screen.made);
+    assert_eq!(screen.breakdown.contract_tricks, 8);
+    assert_eq!(screen.breakdown.overtricks, 1);
+    assert_eq!(screen.options, vec![ResultOption::Replay, ResultOption::Restart]);
+}
+
+#[test]
+fn defeated_contract() {
+    let screen = play_phase_result::build(&contract(), 6);
+    assert_eq!(screen.title, "Contract Defeated");
+    assert_eq!(screen.summary, "8♠ by You: 6 tricks, down 2");
+    assert!(!screen.made);
+    assert_eq!(screen.breakdown.undertricks, 2);
+    assert_eq!(screen.breakdown.net(), -2);
+}
+
+#[test]
+fn no_screen_before_game_over() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(play_phase_result::for_game(&game), None);
+}
+
+#[test]
+fn screen_for_finished_game_counts_final_trick() {
+    let mut game = test_games::game("AC 2H", "3C 3H", "4C AH", "5C 4H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "AH");
+    test_games::play(&mut game, HandIdentifier::West, "4H");
+    assert_eq!(game.tricks_won(PlayerName::User), 1);
+
+    let screen = play_phase_result::for_game(&game).unwrap();
+    assert_eq!(screen.breakdown.contract_tricks, 2);
+    assert_eq!(screen.summary, "8♠ by You: 2 tricks, down 6");
+    assert!(!screen.made);
+}
+
+fn contract() -> Contract {
+    Contract { declarer: PlayerName::User, trump: Some(Suit::Spades), bid: 8 }
+}
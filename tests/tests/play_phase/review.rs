@@ -0,0 +1,100 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use play_phase_rules::play_phase_review::PlayPhaseReview;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn next_step_reconstructs_each_trick() {
+    let mut review = PlayPhaseReview::new(&played_game());
+    let mut states = vec![];
+    while let Some(state) = review.next_step() {
+        states.push(state);
+    }
+    assert_eq!(states.len(), 8);
+    assert_eq!(review.next_step().map(|_| ()), None);
+
+    assert_eq!(states[0].current_trick.cards.len(), 1);
+    assert_eq!(states[0].hand(HandIdentifier::South).count(), 1);
+    assert_eq!(states[3].current_trick.cards.len(), 4);
+    assert!(states[3].completed_tricks.is_empty());
+    assert_eq!(states[4].completed_tricks.len(), 1);
+    assert_eq!(states[4].completed_tricks[0].winner, HandIdentifier::East);
+    assert_eq!(states[4].current_trick.cards[0].card, test_games::card("7D"));
+    assert!(states[7].is_game_over());
+}
+
+#[test]
+fn previous_step_reverses_next_step() {
+    let mut review = PlayPhaseReview::new(&played_game());
+    let forward = (0..5).map(|_| review.next_step().unwrap()).collect::<Vec<_>>();
+    assert_eq!(review.step(), 5);
+    for expected in forward.iter().rev().skip(1) {
+        let state = review.previous_step().unwrap();
+        assert_eq!(state.played_cards().count(), expected.played_cards().count());
+        assert_eq!(state.hands, expected.hands);
+    }
+    let start = review.previous_step().unwrap();
+    assert_eq!(review.step(), 0);
+    assert_eq!(start.played_cards().count(), 0);
+    assert_eq!(start.hand(HandIdentifier::North).count(), 2);
+    assert!(review.previous_step().is_none());
+}
+
+#[test]
+fn state_at_trick_stops_mid_trick() {
+    let review = PlayPhaseReview::new(&played_game());
+    let state = review.state_at_trick(2, 2);
+    assert_eq!(state.completed_tricks.len(), 1);
+    assert_eq!(
+        state.current_trick.cards.iter().map(|played| played.card).collect::<Vec<_>>(),
+        vec![test_games::card("7D"), test_games::card("8D")]
+    );
+    assert_eq!(play_phase_queries::next_to_play(&state), HandIdentifier::West);
+    assert!(state.hand(HandIdentifier::West).any(|card| card == test_games::card("9D")));
+    assert_eq!(review.step(), 0);
+}
+
+#[test]
+fn state_at_trick_counts_prior_tricks() {
+    let mut game = test_games::game("2C 6D", "AC 7D", "4C 8D", "5C 9D").with_prior_tricks(5, 3);
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "7D");
+    let review = PlayPhaseReview::new(&game);
+    let state = review.state_at_trick(6, 1);
+    assert_eq!(state.current_trick_number(), 6);
+    assert_eq!(state.current_trick.cards.len(), 1);
+    let state = review.state_at_trick(7, 0);
+    assert_eq!(state.played_cards().count(), 4);
+    assert_eq!(play_phase_queries::next_to_play(&state), HandIdentifier::East);
+}
+
+fn played_game() -> play_phase_data::PlayPhaseData {
+    let mut game = test_games::game("2C 6D", "AC 7D", "4C 8D", "5C 9D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "7D");
+    test_games::play(&mut game, HandIdentifier::South, "8D");
+    test_games::play(&mut game, HandIdentifier::West, "9D");
+    test_games::play(&mut game, HandIdentifier::North, "6D");
+    game
+}
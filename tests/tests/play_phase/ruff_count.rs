@@ -0,0 +1,40 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn one_ruff_in_scripted_game() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "2S 5H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "2S");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "4H");
+    assert_eq!(play_phase_analysis::ruff_count(&game), 1);
+}
+
+#[test]
+fn no_ruffs_in_no_trump() {
+    let mut game = test_games::game("2C", "3C", "4C", "2S");
+    game.contract.trump = None;
+    test_games::play_out(&mut game);
+    assert_eq!(play_phase_analysis::ruff_count(&game), 0);
+}
@@ -0,0 +1,48 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+use play_phase_data::PlayPhaseData;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn new_game_round_trip() {
+    let game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(3), ..GameConfig::default() });
+    let saved = game.save().unwrap();
+    assert_eq!(PlayPhaseData::load(&saved).unwrap(), game);
+}
+
+#[test]
+fn round_trip_after_plays() {
+    let mut game = test_games::game("2C 2H", "3C 3H", "4C 4H", "5C 5H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    let saved = game.save().unwrap();
+    assert_eq!(PlayPhaseData::load(&saved).unwrap(), game);
+}
+
+#[test]
+fn save_is_stable() {
+    let game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(3), ..GameConfig::default() });
+    let saved = game.save().unwrap();
+    let reloaded = PlayPhaseData::load(&saved).unwrap();
+    assert_eq!(reloaded.save().unwrap(), saved);
+}
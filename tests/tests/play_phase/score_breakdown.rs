@@ -0,0 +1,35 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::ScoreBreakdown;
+
+use crate::test_games;
+
+#[test]
+fn made_with_overtricks() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let breakdown = game.score_breakdown(10);
+    assert_eq!(breakdown, ScoreBreakdown { contract_tricks: 8, overtricks: 2, undertricks: 0 });
+    assert!(breakdown.is_made());
+    assert_eq!(breakdown.net(), 2);
+}
+
+#[test]
+fn defeated() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let breakdown = game.score_breakdown(5);
+    assert_eq!(breakdown, ScoreBreakdown { contract_tricks: 5, overtricks: 0, undertricks: 3 });
+    assert!(!breakdown.is_made());
+    assert_eq!(breakdown.net(), -3);
+}
@@ -0,0 +1,41 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+#[test]
+fn no_side_winning_empty_trick() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(play_phase_queries::side_winning_trick(&game), None);
+}
+
+#[test]
+fn partner_winning() {
+    let mut game = test_games::game("AC", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "AC");
+    assert_eq!(play_phase_queries::side_winning_trick(&game), Some(PlayerName::User));
+}
+
+#[test]
+fn opponent_winning() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    assert_eq!(play_phase_queries::side_winning_trick(&game), Some(PlayerName::Opponent));
+}
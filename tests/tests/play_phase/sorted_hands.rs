@@ -0,0 +1,28 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+use primitives::HandIdentifier;
+
+#[test]
+fn hand_iteration_is_sorted() {
+    let game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(7), ..GameConfig::default() });
+    for hand in enum_iterator::all::<HandIdentifier>() {
+        let cards = game.hand(hand).collect::<Vec<_>>();
+        assert_eq!(cards.len(), 13);
+        assert!(cards.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(cards, game.sorted_hand(hand));
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_analysis;
+
+#[test]
+fn game_values() {
+    assert_eq!(play_phase_analysis::suggested_level(26), 9);
+    assert_eq!(play_phase_analysis::suggested_level(28), 10);
+}
+
+#[test]
+fn slam_values() {
+    assert_eq!(play_phase_analysis::suggested_level(33), 12);
+    assert_eq!(play_phase_analysis::suggested_level(40), 13);
+}
+
+#[test]
+fn low_totals_use_minimum_bid() {
+    assert_eq!(play_phase_analysis::suggested_level(10), 7);
+    assert_eq!(play_phase_analysis::suggested_level(22), 7);
+    assert_eq!(play_phase_analysis::suggested_level(23), 8);
+}
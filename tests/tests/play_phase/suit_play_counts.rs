@@ -0,0 +1,39 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::SuitPlayCount;
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn counts_after_two_tricks() {
+    let mut game = test_games::game("2C 2H 2D", "3C 3H 3D", "4C 4H 4D", "5C 5H 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "4H");
+    test_games::play(&mut game, HandIdentifier::West, "5D");
+
+    let counts = play_phase_queries::suit_play_counts(&game);
+    assert_eq!(counts[&Suit::Clubs], SuitPlayCount { played: 4, outstanding: 0 });
+    assert_eq!(counts[&Suit::Hearts], SuitPlayCount { played: 4, outstanding: 0 });
+    assert_eq!(counts[&Suit::Diamonds], SuitPlayCount { played: 1, outstanding: 3 });
+    assert_eq!(counts[&Suit::Spades], SuitPlayCount { played: 0, outstanding: 0 });
+}
@@ -0,0 +1,52 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+#[test]
+fn swap_twice_is_identity() {
+    let game = test_games::game("2C 3D", "3C 4D", "4C 5D", "5C 6D");
+    assert_eq!(game.swap_partnerships().swap_partnerships(), game);
+}
+
+#[test]
+fn swap_exchanges_hands_and_declarer() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let swapped = game.swap_partnerships();
+    assert_eq!(swapped.declarer(), PlayerName::Opponent);
+    assert!(swapped.hands[&HandIdentifier::West].contains(&test_games::card("4C")));
+    assert!(swapped.hands[&HandIdentifier::East].contains(&test_games::card("2C")));
+}
+
+#[test]
+fn swap_keeps_seating_order() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    let swapped = game.swap_partnerships();
+    let declarer = swapped.declarer().primary_hand();
+    assert!(swapped.hands[&declarer].contains(&test_games::card("4C")));
+    assert!(swapped.hands[&declarer.next()].contains(&test_games::card("5C")));
+    assert!(swapped.hands[&declarer.partner()].contains(&test_games::card("2C")));
+    assert!(swapped.hands[&declarer.next().partner()].contains(&test_games::card("3C")));
+}
+
+#[test]
+fn swap_restarts_from_deal() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    let swapped = game.swap_partnerships();
+    assert!(swapped.is_opening_lead());
+    assert!(swapped.hands[&HandIdentifier::West].contains(&test_games::card("4C")));
+}
@@ -0,0 +1,56 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::HandIdentifier;
+
+use crate::test_games::{self, card};
+
+#[test]
+fn lists_completed_tricks() {
+    let mut game = test_games::game("2C 2D 3S 7S", "3C 3D 3H 4S", "4C 4D 4H 5S", "5C 5D 5H 6S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::West, "5D");
+    test_games::play(&mut game, HandIdentifier::North, "2D");
+    test_games::play(&mut game, HandIdentifier::East, "3D");
+    test_games::play(&mut game, HandIdentifier::South, "4D");
+    test_games::play(&mut game, HandIdentifier::West, "5H");
+    test_games::play(&mut game, HandIdentifier::North, "3S");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "4H");
+    test_games::play(&mut game, HandIdentifier::North, "7S");
+
+    let history = game.trick_history();
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history[0],
+        (
+            1,
+            vec![
+                (HandIdentifier::South, card("4C")),
+                (HandIdentifier::West, card("5C")),
+                (HandIdentifier::North, card("2C")),
+                (HandIdentifier::East, card("3C")),
+            ],
+            HandIdentifier::West
+        )
+    );
+    assert_eq!(history[1].0, 2);
+    assert_eq!(history[1].2, HandIdentifier::West);
+    assert_eq!(history[2].0, 3);
+    assert_eq!(history[2].1[0], (HandIdentifier::West, card("5H")));
+    assert_eq!(history[2].2, HandIdentifier::North);
+}
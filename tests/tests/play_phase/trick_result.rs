@@ -0,0 +1,42 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games;
+
+#[test]
+fn complete_trick_won_by_trump() {
+    let mut game = test_games::game("AC", "3C", "4C", "2S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "2S");
+    test_games::play(&mut game, HandIdentifier::North, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(
+        play_phase_queries::trick_result(&game.current_trick, Some(Suit::Spades)),
+        Some((HandIdentifier::West, test_games::card("2S")))
+    );
+    assert_eq!(
+        play_phase_queries::trick_result(&game.current_trick, None),
+        Some((HandIdentifier::North, test_games::card("AC")))
+    );
+}
+
+#[test]
+fn incomplete_trick_has_no_result() {
+    let mut game = test_games::game("AC", "3C", "4C", "2S");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert_eq!(play_phase_queries::trick_result(&game.current_trick, Some(Suit::Spades)), None);
+}
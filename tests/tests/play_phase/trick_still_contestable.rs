@@ -0,0 +1,47 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn ace_played_decides_trick() {
+    let mut game = test_games::game("2C 3D", "KC 3H", "AC 4D", "QC 5D");
+    game.contract.trump = None;
+    test_games::play(&mut game, HandIdentifier::South, "AC");
+    assert!(!play_phase_queries::trick_still_contestable(&game));
+}
+
+#[test]
+fn higher_card_to_come_is_contestable() {
+    let mut game = test_games::game("2C 3D", "KC 3H", "4C 4D", "QC 5D");
+    game.contract.trump = None;
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(play_phase_queries::trick_still_contestable(&game));
+}
+
+#[test]
+fn void_hand_with_trump_can_contest() {
+    let mut game = test_games::game("2C 3D", "2S 3H", "AC 4D", "QC 5D");
+    test_games::play(&mut game, HandIdentifier::South, "AC");
+    assert!(play_phase_queries::trick_still_contestable(&game));
+}
+
+#[test]
+fn empty_trick_is_not_contestable() {
+    let game = test_games::game("2C 3D", "KC 3H", "AC 4D", "QC 5D");
+    assert!(!play_phase_queries::trick_still_contestable(&game));
+}
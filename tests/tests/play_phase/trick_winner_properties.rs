@@ -0,0 +1,61 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::{PlayedCard, Trick};
+use play_phase_rules::play_phase_queries;
+use primitives::{Card, HandIdentifier, Rank, Suit};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Builds a trick of four distinct random cards, led by a random hand.
+fn random_trick(rng: &mut StdRng) -> Trick {
+    let mut deck = enum_iterator::all::<Suit>()
+        .flat_map(|suit| enum_iterator::all::<Rank>().map(move |rank| Card::new(suit, rank)))
+        .collect::<Vec<_>>();
+    deck.shuffle(rng);
+    let leader = HandIdentifier::from_seat_index(rng.gen_range(0..4));
+    Trick {
+        cards: deck[..4]
+            .iter()
+            .enumerate()
+            .map(|(i, &card)| PlayedCard { played_by: leader.rotate(i), card })
+            .collect(),
+    }
+}
+
+#[test]
+fn trick_winner_invariants_hold_for_random_tricks() {
+    let mut rng = StdRng::seed_from_u64(2233);
+    let trumps =
+        [None, Some(Suit::Clubs), Some(Suit::Diamonds), Some(Suit::Hearts), Some(Suit::Spades)];
+    for _ in 0..1000 {
+        let trick = random_trick(&mut rng);
+        let trump = *trumps.choose(&mut rng).unwrap();
+        let lead = trick.cards[0].card.suit;
+
+        let winner = play_phase_queries::trick_winner(&trick, trump);
+        let winning = trick.cards.iter().find(|played| played.played_by == winner).unwrap().card;
+        assert_eq!(play_phase_queries::trick_result(&trick, trump), Some((winner, winning)));
+
+        let trumps_played = trick.cards.iter().filter(|played| Some(played.card.suit) == trump);
+        let expected_suit = if trumps_played.count() > 0 { trump.unwrap() } else { lead };
+        assert_eq!(winning.suit, expected_suit);
+        for played in &trick.cards {
+            if played.card.suit == expected_suit {
+                assert!(played.card.rank <= winning.rank);
+            }
+        }
+    }
+}
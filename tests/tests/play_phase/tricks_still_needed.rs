@@ -0,0 +1,72 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::GameEvent;
+use play_phase_rules::play_phase_analysis;
+use play_phase_rules::play_phase_review::PlayPhaseReview;
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+#[test]
+fn full_bid_needed_at_start() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(game.tricks_still_needed(0), 8);
+}
+
+#[test]
+fn fewer_needed_after_tricks() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(game.tricks_still_needed(5), 3);
+    assert_eq!(game.tricks_still_needed(8), 0);
+    assert_eq!(game.tricks_still_needed(10), 0);
+}
+
+#[test]
+fn declarer_wins_just_made_contract() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(game.winning_side(8), PlayerName::User);
+}
+
+#[test]
+fn defender_wins_just_set_contract() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(game.winning_side(7), PlayerName::Opponent);
+}
+
+#[test]
+fn prior_tricks_count_towards_contract() {
+    let game = test_games::game("2C", "3C", "4C", "5C").with_prior_tricks(8, 5);
+    assert!(game.completed_tricks.is_empty());
+    assert_eq!(game.current_trick_number(), 9);
+    assert_eq!(game.tricks_won(PlayerName::User), 5);
+    assert_eq!(game.tricks_won(PlayerName::Opponent), 3);
+    assert_eq!(game.tricks_still_needed(game.tricks_won(PlayerName::User)), 3);
+}
+
+#[test]
+fn replay_after_prior_tricks() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C").with_prior_tricks(12, 0);
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    assert_eq!(play_phase_analysis::declarer_tricks(&game), 0);
+    assert_eq!(play_phase_analysis::decisive_trick(&game), Some(11));
+    assert_eq!(game.events.last(), Some(&GameEvent::ContractDefeated));
+
+    let mut review = PlayPhaseReview::new(&game);
+    while review.next_step().is_some() {}
+    assert_eq!(review.current(), game);
+}
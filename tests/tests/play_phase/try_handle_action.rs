@@ -0,0 +1,75 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_data::PlayPhaseAction;
+use play_phase_rules::play_phase_actions;
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+fn play_card(hand: HandIdentifier, card: &str) -> PlayPhaseAction {
+    PlayPhaseAction::PlayCard(PlayerName::User, hand, test_games::card(card))
+}
+
+#[test]
+fn legal_play_succeeds() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    assert!(play_phase_actions::try_handle_action(
+        &mut game,
+        play_card(HandIdentifier::South, "4C")
+    )
+    .is_ok());
+    assert_eq!(game.current_trick.lead_card(), Some(test_games::card("4C")));
+}
+
+#[test]
+fn card_not_in_hand_is_error() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    let before = game.clone();
+    assert!(play_phase_actions::try_handle_action(
+        &mut game,
+        play_card(HandIdentifier::South, "AS")
+    )
+    .is_err());
+    assert_eq!(game, before);
+}
+
+#[test]
+fn out_of_turn_is_error() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    assert!(play_phase_actions::try_handle_action(
+        &mut game,
+        play_card(HandIdentifier::West, "5C")
+    )
+    .is_err());
+}
+
+#[test]
+fn failing_to_follow_suit_is_error() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C 5H");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(play_phase_actions::try_handle_action(
+        &mut game,
+        play_card(HandIdentifier::West, "5H")
+    )
+    .is_err());
+}
+
+#[test]
+fn continue_before_trick_is_complete_is_error() {
+    let mut game = test_games::game("2C 2D", "3C 3D", "4C 4D", "5C 5D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert!(play_phase_actions::try_handle_action(&mut game, PlayPhaseAction::ContinueToNextTrick)
+        .is_err());
+}
@@ -0,0 +1,60 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::HandIdentifier;
+
+use crate::test_games;
+
+#[test]
+fn turn_order_before_first_lead() {
+    let game = test_games::game("2C", "3C", "4C", "5C");
+    assert_eq!(
+        play_phase_queries::upcoming_turn_order(&game),
+        vec![
+            HandIdentifier::South,
+            HandIdentifier::West,
+            HandIdentifier::North,
+            HandIdentifier::East
+        ]
+    );
+}
+
+#[test]
+fn turn_order_mid_trick() {
+    let mut game = test_games::game("2C", "3C", "4C", "5C");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    assert_eq!(
+        play_phase_queries::upcoming_turn_order(&game),
+        vec![HandIdentifier::West, HandIdentifier::North, HandIdentifier::East]
+    );
+}
+
+#[test]
+fn turn_order_at_trick_boundary() {
+    let mut game = test_games::game("2C 6D", "AC 7D", "4C 8D", "5C 9D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "2C");
+    test_games::play(&mut game, HandIdentifier::East, "AC");
+    assert_eq!(
+        play_phase_queries::upcoming_turn_order(&game),
+        vec![
+            HandIdentifier::East,
+            HandIdentifier::South,
+            HandIdentifier::West,
+            HandIdentifier::North
+        ]
+    );
+}
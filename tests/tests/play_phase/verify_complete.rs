@@ -0,0 +1,49 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use auction_phase_data::GameConfig;
+
+use crate::test_games;
+
+#[test]
+fn played_out_seeded_game_is_complete() {
+    let mut game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(5), ..GameConfig::default() });
+    test_games::play_out(&mut game);
+    assert!(game.verify_complete().is_ok());
+}
+
+#[test]
+fn played_out_reduced_deal_is_complete() {
+    let config = GameConfig { seed: Some(5), hand_size: 5, ..GameConfig::default() };
+    let mut game = auction_phase_mutations::new_game(&config);
+    test_games::play_out(&mut game);
+    assert!(game.verify_complete().is_ok());
+}
+
+#[test]
+fn truncated_game_is_not_complete() {
+    let mut game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(5), ..GameConfig::default() });
+    test_games::play_out(&mut game);
+    game.completed_tricks[3].trick.cards.pop();
+    assert!(game.verify_complete().is_err());
+}
+
+#[test]
+fn unfinished_game_is_not_complete() {
+    let game =
+        auction_phase_mutations::new_game(&GameConfig { seed: Some(5), ..GameConfig::default() });
+    assert!(game.verify_complete().is_err());
+}
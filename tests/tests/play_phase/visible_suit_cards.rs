@@ -0,0 +1,47 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use play_phase_rules::play_phase_queries;
+use primitives::{HandIdentifier, Suit};
+
+use crate::test_games::{self, card};
+
+#[test]
+fn remaining_clubs_after_a_trick() {
+    let mut game = test_games::game("AC 5C 2H", "KC 3H 4H", "QC 2C 5H", "JC 6C 7H");
+    test_games::play(&mut game, HandIdentifier::South, "2C");
+    test_games::play(&mut game, HandIdentifier::West, "6C");
+    test_games::play(&mut game, HandIdentifier::North, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "KC");
+    assert_eq!(
+        play_phase_queries::visible_suit_cards(&game, Suit::Clubs),
+        vec![
+            (HandIdentifier::South, card("QC")),
+            (HandIdentifier::North, card("5C")),
+            (HandIdentifier::South, card("2C")),
+            (HandIdentifier::West, card("6C")),
+            (HandIdentifier::North, card("AC")),
+            (HandIdentifier::East, card("KC")),
+        ]
+    );
+}
+
+#[test]
+fn dummy_hidden_before_opening_lead() {
+    let game = test_games::game("AC 5C 2H", "KC 3H 4H", "QC 2C 5H", "JC 6C 7H");
+    assert_eq!(
+        play_phase_queries::visible_suit_cards(&game, Suit::Clubs),
+        vec![(HandIdentifier::South, card("2C")), (HandIdentifier::South, card("QC")),]
+    );
+}
@@ -0,0 +1,41 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::{HandIdentifier, PlayerName};
+
+use crate::test_games;
+
+#[test]
+fn north_and_south_credit_same_side() {
+    let mut game = test_games::game("AC 2H 5D", "3C 3H 6D", "4C AH 7D", "5C 4H 8D");
+    test_games::play(&mut game, HandIdentifier::South, "4C");
+    test_games::play(&mut game, HandIdentifier::West, "5C");
+    test_games::play(&mut game, HandIdentifier::North, "AC");
+    test_games::play(&mut game, HandIdentifier::East, "3C");
+    test_games::play(&mut game, HandIdentifier::North, "2H");
+    test_games::play(&mut game, HandIdentifier::East, "3H");
+    test_games::play(&mut game, HandIdentifier::South, "AH");
+    test_games::play(&mut game, HandIdentifier::West, "4H");
+    test_games::play(&mut game, HandIdentifier::South, "7D");
+
+    let winners =
+        game.completed_tricks.iter().map(|completed| completed.winner).collect::<Vec<_>>();
+    assert_eq!(winners, vec![HandIdentifier::North, HandIdentifier::South]);
+    assert!(game
+        .completed_tricks
+        .iter()
+        .all(|completed| completed.winning_side() == PlayerName::User));
+    assert_eq!(game.tricks_won(PlayerName::User), 2);
+    assert_eq!(game.tricks_won(PlayerName::Opponent), 0);
+}
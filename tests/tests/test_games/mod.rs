@@ -0,0 +1,123 @@
+// Copyright © Oak 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for constructing games with known hands in tests.
+
+use std::collections::{BTreeSet, HashMap};
+
+use auction_phase_data::{Contract, GameConfig, Rules};
+use play_phase_data::{PlayPhaseAction, PlayPhaseData, PriorTricks, Trick};
+use play_phase_rules::{play_phase_actions, play_phase_queries};
+use primitives::{Card, HandIdentifier, PlayerName, Rank, Suit};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Parses a card written as rank followed by suit, e.g. "AS", "10H" or "2C".
+pub fn card(s: &str) -> Card {
+    let (rank, suit) = s.split_at(s.len() - 1);
+    let rank = match rank {
+        "2" => Rank::Two,
+        "3" => Rank::Three,
+        "4" => Rank::Four,
+        "5" => Rank::Five,
+        "6" => Rank::Six,
+        "7" => Rank::Seven,
+        "8" => Rank::Eight,
+        "9" => Rank::Nine,
+        "10" | "T" => Rank::Ten,
+        "J" => Rank::Jack,
+        "Q" => Rank::Queen,
+        "K" => Rank::King,
+        "A" => Rank::Ace,
+        _ => panic!("Invalid rank {rank}"),
+    };
+    let suit = match suit {
+        "C" => Suit::Clubs,
+        "D" => Suit::Diamonds,
+        "H" => Suit::Hearts,
+        "S" => Suit::Spades,
+        _ => panic!("Invalid suit {suit}"),
+    };
+    Card::new(suit, rank)
+}
+
+/// Parses a whitespace-separated list of cards, e.g. "AS KS 2C".
+pub fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(card).collect()
+}
+
+/// Builds a game with the provided hands and a contract of 8 tricks in spades
+/// for the user, matching the default `GameConfig` contract.
+pub fn game(north: &str, east: &str, south: &str, west: &str) -> PlayPhaseData {
+    let mut hands = HashMap::new();
+    hands.insert(HandIdentifier::North, hand(north));
+    hands.insert(HandIdentifier::East, hand(east));
+    hands.insert(HandIdentifier::South, hand(south));
+    hands.insert(HandIdentifier::West, hand(west));
+    PlayPhaseData {
+        hands,
+        current_trick: Trick::default(),
+        completed_tricks: vec![],
+        prior_tricks: PriorTricks::default(),
+        contract: Contract { declarer: PlayerName::User, trump: Some(Suit::Spades), bid: 8 },
+        rules: Rules::default(),
+        events: vec![],
+    }
+}
+
+/// Plays a card from the indicated hand, panicking if it is not legal to do so.
+pub fn play(game: &mut PlayPhaseData, hand: HandIdentifier, card: &str) {
+    play_phase_actions::handle_action(
+        game,
+        PlayPhaseAction::PlayCard(hand.player_name(), hand, self::card(card)),
+    );
+}
+
+/// Plays out the rest of the game, with each hand playing its lowest legal
+/// card.
+pub fn play_out(game: &mut PlayPhaseData) {
+    while !game.is_game_over() {
+        let hand = play_phase_queries::next_to_play(game);
+        let card = play_phase_queries::legal_plays(game, hand)[0];
+        play_phase_actions::handle_action(
+            game,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+    }
+}
+
+/// Deals a game with a random trump suit (or no trump) and plays a random
+/// number of random legal cards from it, leaving at least one card to play.
+pub fn random_position(rng: &mut StdRng) -> PlayPhaseData {
+    let mut config = GameConfig { seed: Some(rng.gen()), ..GameConfig::default() };
+    config.contract.trump =
+        *[None, Some(Suit::Clubs), Some(Suit::Diamonds), Some(Suit::Hearts), Some(Suit::Spades)]
+            .choose(rng)
+            .unwrap();
+    let mut game = auction_phase_mutations::new_game(&config);
+    for _ in 0..rng.gen_range(0..52) {
+        let hand = play_phase_queries::next_to_play(&game);
+        let card = *play_phase_queries::legal_plays(&game, hand).choose(rng).unwrap();
+        play_phase_actions::handle_action(
+            &mut game,
+            PlayPhaseAction::PlayCard(hand.player_name(), hand, card),
+        );
+    }
+    game
+}
+
+fn hand(s: &str) -> BTreeSet<Card> {
+    cards(s).into_iter().collect()
+}